@@ -0,0 +1,276 @@
+use std::{collections::BTreeMap, fmt::Display, fs, io, path::Path, str::FromStr, time::Duration};
+
+use aoc23::{
+    bench::{measure, Stats},
+    Part, Puzzle,
+};
+
+use crate::unwind;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Stage {
+    Parse,
+    Part(Part),
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stage::Parse => f.write_str("parse"),
+            Stage::Part(part) => part.fmt(f),
+        }
+    }
+}
+
+impl FromStr for Stage {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Stage::Parse),
+            _ => s.parse().map(Stage::Part),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Measurement {
+    pub day: u8,
+    pub stage: Stage,
+    pub stats: Stats,
+}
+
+/// What [`bench`] measured of a day
+#[derive(Debug)]
+pub struct Bench {
+    pub measurements: Vec<Measurement>,
+    /// Parts that failed or panicked instead of being measured
+    pub failures: Vec<(Part, String)>,
+}
+
+/// Times parsing and each solved part of a day separately.
+/// Only fails if the input doesn't parse, a part that fails or panics is left out.
+pub fn bench(
+    puzzle: &dyn Puzzle,
+    input: &str,
+    iterations: usize,
+    budget: Duration,
+) -> Result<Bench, String> {
+    let day = puzzle.day();
+    let parsed = unwind(|| puzzle.parse(input))?;
+
+    let mut bench = Bench {
+        measurements: vec![Measurement {
+            day,
+            stage: Stage::Parse,
+            stats: measure(iterations, budget, || puzzle.parse(input)),
+        }],
+        failures: Vec::new(),
+    };
+
+    for part in Part::BOTH {
+        let measured = unwind(|| {
+            if puzzle.solve(part, parsed.as_ref())?.is_none() {
+                return Ok(None);
+            }
+            Ok(Some(measure(iterations, budget, || {
+                puzzle.solve(part, parsed.as_ref())
+            })))
+        });
+        match measured {
+            Ok(Some(stats)) => bench.measurements.push(Measurement {
+                day,
+                stage: Stage::Part(part),
+                stats,
+            }),
+            Ok(None) => {}
+            Err(error) => bench.failures.push((part, error)),
+        }
+    }
+
+    Ok(bench)
+}
+
+/// Earlier measurements to compare against, stored as one line of nanoseconds per day and stage.
+#[derive(Debug, Default)]
+pub struct Baseline {
+    stats: BTreeMap<(u8, Stage), Stats>,
+}
+
+impl Baseline {
+    const HEADER: &'static str = "# day stage samples min median mean std_dev (ns)";
+
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let content = fs::read_to_string(path)?;
+
+        let mut stats = BTreeMap::new();
+        for (number, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid = || {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "{}:{}: expected `{}`, got `{line}`",
+                        path.display(),
+                        number + 1,
+                        &Self::HEADER[2..]
+                    ),
+                )
+            };
+            let fields: Vec<_> = line.split_whitespace().collect();
+            let [day, stage, samples, min, median, mean, std_dev] = fields[..] else {
+                return Err(invalid());
+            };
+            let nanos = |s: &str| s.parse().map(Duration::from_nanos).map_err(|_| invalid());
+
+            stats.insert(
+                (
+                    day.parse().map_err(|_| invalid())?,
+                    stage.parse().map_err(|_| invalid())?,
+                ),
+                Stats {
+                    samples: samples.parse().map_err(|_| invalid())?,
+                    min: nanos(min)?,
+                    median: nanos(median)?,
+                    mean: nanos(mean)?,
+                    std_dev: nanos(std_dev)?,
+                },
+            );
+        }
+
+        Ok(Baseline { stats })
+    }
+
+    pub fn save(path: &Path, measurements: &[Measurement]) -> io::Result<()> {
+        let mut content = format!("{}\n", Self::HEADER);
+        for Measurement { day, stage, stats } in measurements {
+            content.push_str(&format!(
+                "{day} {stage} {} {} {} {} {}\n",
+                stats.samples,
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.mean.as_nanos(),
+                stats.std_dev.as_nanos(),
+            ));
+        }
+        fs::write(path, content)
+    }
+
+    pub fn get(&self, day: u8, stage: Stage) -> Option<&Stats> {
+        self.stats.get(&(day, stage))
+    }
+}
+
+/// Relative change of the median, negative is faster
+pub fn change(baseline: &Stats, current: &Stats) -> f64 {
+    current.median.as_secs_f64() / baseline.median.as_secs_f64() - 1.0
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc23::{Registry, Solution};
+
+    use super::*;
+
+    /// Part one fails, part two panics on odd inputs
+    struct Fragile;
+
+    impl Solution for Fragile {
+        const DAY: u8 = 26;
+        const TITLE: &'static str = "Fragile";
+
+        type Parsed = u32;
+        type One = u32;
+        type Two = u32;
+
+        fn parse(input: &str) -> aoc23::Result<Self::Parsed> {
+            Ok(input.parse()?)
+        }
+
+        fn part_one(_: &Self::Parsed) -> aoc23::Result<Self::One> {
+            Err("no answer".into())
+        }
+
+        fn part_two(parsed: &Self::Parsed) -> aoc23::Result<Self::Two> {
+            assert!(parsed % 2 == 0, "odd");
+            Ok(*parsed / 2)
+        }
+    }
+
+    #[test]
+    fn failing_parts_keep_the_other_measurements() {
+        let mut registry = Registry::default();
+        registry.register::<Fragile>();
+        let puzzle = registry.get(26).unwrap();
+        let stages =
+            |bench: &Bench| -> Vec<Stage> { bench.measurements.iter().map(|m| m.stage).collect() };
+
+        let even = bench(puzzle, "42", 2, Duration::from_secs(1)).unwrap();
+        assert_eq!(stages(&even), [Stage::Parse, Stage::Part(Part::Two)]);
+        assert_eq!(even.failures, [(Part::One, "no answer".to_string())]);
+
+        let odd = bench(puzzle, "41", 2, Duration::from_secs(1)).unwrap();
+        assert_eq!(stages(&odd), [Stage::Parse]);
+        assert_eq!(
+            odd.failures,
+            [
+                (Part::One, "no answer".to_string()),
+                (Part::Two, "panicked: odd".to_string())
+            ]
+        );
+
+        assert!(bench(puzzle, "forty-one", 2, Duration::from_secs(1)).is_err());
+    }
+
+    #[test]
+    fn roundtrip() {
+        let path = std::env::temp_dir().join(format!("aoc-baseline-{}.txt", std::process::id()));
+        let stats = Stats {
+            samples: 3,
+            min: Duration::from_nanos(10),
+            median: Duration::from_nanos(20),
+            mean: Duration::from_nanos(25),
+            std_dev: Duration::from_nanos(5),
+        };
+        let measurements = [
+            Measurement {
+                day: 5,
+                stage: Stage::Parse,
+                stats,
+            },
+            Measurement {
+                day: 5,
+                stage: Stage::Part(Part::Two),
+                stats,
+            },
+        ];
+
+        Baseline::save(&path, &measurements).unwrap();
+        let baseline = Baseline::load(&path).unwrap();
+
+        assert_eq!(baseline.get(5, Stage::Parse), Some(&stats));
+        assert_eq!(baseline.get(5, Stage::Part(Part::Two)), Some(&stats));
+        assert_eq!(baseline.get(5, Stage::Part(Part::One)), None);
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn change_of_median() {
+        let stats = |median| Stats {
+            samples: 1,
+            min: median,
+            median,
+            mean: median,
+            std_dev: Duration::ZERO,
+        };
+        let baseline = stats(Duration::from_millis(10));
+
+        assert_eq!(change(&baseline, &stats(Duration::from_millis(5))), -0.5);
+        assert_eq!(change(&baseline, &stats(Duration::from_millis(15))), 0.5);
+    }
+}
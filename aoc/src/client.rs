@@ -0,0 +1,461 @@
+use std::{error::Error, fmt::Display, fs, io, path::PathBuf};
+
+use aoc23::{Inputs, Part};
+use ureq::{
+    typestate::{WithBody, WithoutBody},
+    Agent, RequestBuilder,
+};
+
+/// Talks to adventofcode.com on behalf of a logged in user.
+pub struct Client {
+    base: String,
+    session: Option<String>,
+    agent: Agent,
+}
+
+impl Client {
+    pub const BASE: &'static str = "https://adventofcode.com";
+    /// As asked for by the AoC automation guidelines, so the maintainers know who to contact
+    pub const USER_AGENT: &'static str = "github.com/benthillerkus/advent-of-code-2023";
+    pub const YEAR: u16 = 2023;
+
+    pub fn new(session: impl Into<String>) -> Self {
+        Client {
+            session: Some(session.into()),
+            ..Self::anonymous()
+        }
+    }
+
+    /// Can only read the public puzzle pages
+    pub fn anonymous() -> Self {
+        let agent = Agent::config_builder()
+            .user_agent(Self::USER_AGENT)
+            .http_status_as_error(false)
+            .build()
+            .into();
+
+        Client {
+            base: Self::BASE.to_owned(),
+            session: None,
+            agent,
+        }
+    }
+
+    /// Sends requests somewhere other than adventofcode.com, e.g. a local stub server
+    pub fn with_base(mut self, base: impl Into<String>) -> Self {
+        self.base = base.into().trim_end_matches('/').to_owned();
+        self
+    }
+
+    fn url(&self, day: u8, path: &str) -> String {
+        format!("{}/{}/day/{day}{path}", self.base, Self::YEAR)
+    }
+
+    fn get(&self, url: String) -> RequestBuilder<WithoutBody> {
+        let request = self.agent.get(url);
+        match &self.session {
+            Some(session) => request.header("Cookie", format!("session={session}")),
+            None => request,
+        }
+    }
+
+    fn post(&self, url: String) -> RequestBuilder<WithBody> {
+        let request = self.agent.post(url);
+        match &self.session {
+            Some(session) => request.header("Cookie", format!("session={session}")),
+            None => request,
+        }
+    }
+
+    /// The name of the puzzle, from the `--- Day 1: Trebuchet?! ---` heading of its page
+    pub fn title(&self, day: u8) -> Result<String, ClientError> {
+        let mut response = self.get(self.url(day, "")).call()?;
+
+        let status = response.status().as_u16();
+        let body = response.body_mut().read_to_string()?;
+        if status != 200 {
+            return Err(ClientError::Status {
+                status,
+                body: body.trim().to_owned(),
+            });
+        }
+
+        body.split_once("<h2>")
+            .and_then(|(_, rest)| rest.split_once("</h2>"))
+            .and_then(|(heading, _)| heading.split_once(": "))
+            .map(|(_, title)| title.trim_end_matches('-').trim().to_owned())
+            .ok_or_else(|| ClientError::Unexpected("no title on the puzzle page".to_owned()))
+    }
+
+    /// Downloads the input for `day`, always hitting the server
+    pub fn input(&self, day: u8) -> Result<String, ClientError> {
+        let mut response = self.get(self.url(day, "/input")).call()?;
+
+        let status = response.status().as_u16();
+        let body = response.body_mut().read_to_string()?;
+        if status == 200 {
+            Ok(body)
+        } else {
+            Err(ClientError::Status {
+                status,
+                body: body.trim().to_owned(),
+            })
+        }
+    }
+
+    /// Makes sure the input for `day` is on disk, only downloading it if there's no copy yet
+    pub fn fetch(&self, inputs: &Inputs, day: u8) -> Result<Fetched, ClientError> {
+        if let Some(path) = inputs.find(day) {
+            return Ok(Fetched::Cached(path));
+        }
+
+        let input = self.input(day)?;
+        let path = inputs.cache_path(day);
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&path, input)?;
+
+        Ok(Fetched::Downloaded(path))
+    }
+
+    /// Posts an answer, the server decides whether it's right
+    pub fn submit(&self, day: u8, part: Part, answer: &str) -> Result<Verdict, ClientError> {
+        let mut response = self
+            .post(self.url(day, "/answer"))
+            .send_form([("level", part.to_string().as_str()), ("answer", answer)])?;
+
+        let status = response.status().as_u16();
+        let body = response.body_mut().read_to_string()?;
+        if status == 200 {
+            Ok(Verdict::parse(&body))
+        } else {
+            Err(ClientError::Status {
+                status,
+                body: body.trim().to_owned(),
+            })
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Accepted,
+    TooHigh,
+    TooLow,
+    /// Wrong, but the server didn't say in which direction
+    Wrong,
+    /// Answered too recently, with the time left to wait if the server mentioned it
+    RateLimited(Option<String>),
+    /// The part has already been solved or isn't unlocked yet
+    WrongLevel,
+    /// Anything else, with the text of the response
+    Unknown(String),
+}
+
+impl Verdict {
+    /// Reads the `<article>` of the page the server answers a submission with
+    pub fn parse(html: &str) -> Self {
+        let article = match (html.find("<article>"), html.find("</article>")) {
+            (Some(start), Some(end)) if start < end => &html[start + "<article>".len()..end],
+            _ => html,
+        };
+        let text = strip_tags(article);
+
+        if text.contains("That's the right answer") {
+            Verdict::Accepted
+        } else if text.contains("answer is too high") {
+            Verdict::TooHigh
+        } else if text.contains("answer is too low") {
+            Verdict::TooLow
+        } else if text.contains("That's not the right answer") {
+            Verdict::Wrong
+        } else if text.contains("You gave an answer too recently") {
+            let wait = text
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .map(|(wait, _)| wait.to_owned());
+            Verdict::RateLimited(wait)
+        } else if text.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown(text)
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Accepted => f.write_str("accepted"),
+            Verdict::TooHigh => f.write_str("wrong, too high"),
+            Verdict::TooLow => f.write_str("wrong, too low"),
+            Verdict::Wrong => f.write_str("wrong"),
+            Verdict::RateLimited(Some(wait)) => write!(f, "rate limited, wait {wait}"),
+            Verdict::RateLimited(None) => f.write_str("rate limited"),
+            Verdict::WrongLevel => f.write_str("already solved or not unlocked yet"),
+            Verdict::Unknown(text) => write!(f, "unexpected response: {text}"),
+        }
+    }
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    /// There already was an input, nothing was requested
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Overrides the session file
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+/// `~/.config/aoc23/session` on Linux
+pub fn session_file() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("aoc23").join("session"))
+}
+
+/// The value of the `session` cookie of a browser that's logged in to adventofcode.com
+pub fn session() -> Result<String, ClientError> {
+    if let Ok(session) = std::env::var(SESSION_ENV) {
+        return Ok(session.trim().to_owned());
+    }
+
+    let path = session_file();
+    match path.as_ref().map(fs::read_to_string) {
+        Some(Ok(session)) if !session.trim().is_empty() => Ok(session.trim().to_owned()),
+        _ => Err(ClientError::NoSession(path)),
+    }
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+    NoSession(Option<PathBuf>),
+    Http(ureq::Error),
+    Status {
+        status: u16,
+        body: String,
+    },
+    /// The server answered with something that doesn't look like AoC
+    Unexpected(String),
+    Io(io::Error),
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::NoSession(path) => {
+                write!(f, "no session token, set ${SESSION_ENV}")?;
+                if let Some(path) = path {
+                    write!(f, " or put it in {}", path.display())?;
+                }
+                Ok(())
+            }
+            ClientError::Http(error) => write!(f, "request failed: {error}"),
+            ClientError::Status { status, body } => write!(f, "server answered {status}: {body}"),
+            ClientError::Unexpected(what) => f.write_str(what),
+            ClientError::Io(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl Error for ClientError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ClientError::Http(error) => Some(error),
+            ClientError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<ureq::Error> for ClientError {
+    fn from(error: ureq::Error) -> Self {
+        ClientError::Http(error)
+    }
+}
+
+impl From<io::Error> for ClientError {
+    fn from(error: io::Error) -> Self {
+        ClientError::Io(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    use super::*;
+
+    /// Answers a single request with `status` and `body`, handing back the raw request
+    fn stub(status: u16, body: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        length = value.trim().parse().unwrap();
+                    }
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+            let mut content = vec![0; length];
+            reader.read_exact(&mut content).unwrap();
+            request.push_str(&String::from_utf8(content).unwrap());
+
+            write!(
+                reader.get_mut(),
+                "HTTP/1.1 {status} Whatever\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            request
+        });
+
+        (base, handle)
+    }
+
+    fn scratch(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-client-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn input() {
+        let (base, server) = stub(200, "1abc2\n");
+        let client = Client::new("cookie").with_base(base);
+
+        assert_eq!(client.input(1).unwrap(), "1abc2\n");
+
+        let request = server.join().unwrap().to_lowercase();
+        assert!(request.starts_with("get /2023/day/1/input "));
+        assert!(request.contains("cookie: session=cookie"));
+        assert!(request.contains(&format!("user-agent: {}", Client::USER_AGENT)));
+    }
+
+    #[test]
+    fn input_not_unlocked() {
+        let (base, _server) = stub(404, "Not Found");
+        let client = Client::new("cookie").with_base(base);
+
+        assert!(matches!(
+            client.input(25),
+            Err(ClientError::Status { status: 404, .. })
+        ));
+    }
+
+    #[test]
+    fn fetch_caches() {
+        let dir = scratch("fetch");
+        let inputs = Inputs::new(&dir);
+        let (base, _server) = stub(200, "0 3 6 9 12 15\n");
+        let client = Client::new("cookie").with_base(base);
+
+        let fetched = client.fetch(&inputs, 9).unwrap();
+        assert_eq!(fetched, Fetched::Downloaded(dir.join("9.txt")));
+        assert_eq!(inputs.load(9).unwrap(), "0 3 6 9 12 15\n");
+
+        // the stub only answers once, a second request would fail
+        let fetched = client.fetch(&inputs, 9).unwrap();
+        assert_eq!(fetched, Fetched::Cached(dir.join("9.txt")));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn title() {
+        let (base, server) = stub(
+            200,
+            "<main><article class=\"day-desc\"><h2>--- Day 11: Cosmic Expansion ---</h2><p>You continue</p></article></main>",
+        );
+        let client = Client::anonymous().with_base(base);
+
+        assert_eq!(client.title(11).unwrap(), "Cosmic Expansion");
+
+        let request = server.join().unwrap().to_lowercase();
+        assert!(request.starts_with("get /2023/day/11 "));
+        assert!(!request.contains("cookie"));
+    }
+
+    #[test]
+    fn submit() {
+        let (base, server) = stub(
+            200,
+            "<main><article><p>That's the right answer! You are <em>one gold star</em> closer.</p></article></main>",
+        );
+        let client = Client::new("cookie").with_base(base);
+
+        assert_eq!(
+            client.submit(7, Part::Two, "5905").unwrap(),
+            Verdict::Accepted
+        );
+
+        let request = server.join().unwrap();
+        assert!(request
+            .to_lowercase()
+            .starts_with("post /2023/day/7/answer "));
+        assert!(request.ends_with("level=2&answer=5905"));
+    }
+
+    #[test]
+    fn verdicts() {
+        let page =
+            |text: &str| format!("<html><main><article><p>{text}</p></article></main></html>");
+
+        assert_eq!(
+            Verdict::parse(&page("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; there are also some general tips on the <a href=\"/2023/about\">about page</a>. Please wait one minute before trying again. <a href=\"/2023/day/5\">[Return to Day 5]</a>")),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            Verdict::parse(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            Verdict::parse(&page("That's not the right answer.")),
+            Verdict::Wrong
+        );
+        assert_eq!(
+            Verdict::parse(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 36s left to wait. <a href=\"/2023/day/5\">[Return to Day 5]</a>")),
+            Verdict::RateLimited(Some("36s".to_owned()))
+        );
+        assert_eq!(
+            Verdict::parse(&page("You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2023/day/5\">[Return to Day 5]</a>")),
+            Verdict::WrongLevel
+        );
+        assert!(matches!(
+            Verdict::parse("<html></html>"),
+            Verdict::Unknown(_)
+        ));
+    }
+}
@@ -0,0 +1,172 @@
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use aoc23::{Inputs, Part};
+
+/// Answers that adventofcode.com has accepted, stored as `<day> <part> <answer>` lines.
+#[derive(Debug)]
+pub struct Ledger {
+    path: PathBuf,
+    answers: BTreeMap<(u8, Part), String>,
+}
+
+impl Ledger {
+    pub const FILE_NAME: &'static str = "answers.txt";
+
+    /// The ledger beside `day`'s input, answers only make sense for the input they were given for.
+    /// Beside where a fetched input would be cached if there's no input yet.
+    pub fn for_day(inputs: &Inputs, day: u8) -> io::Result<Self> {
+        let input = inputs.find(day).unwrap_or_else(|| inputs.cache_path(day));
+        Self::load(input.with_file_name(Self::FILE_NAME))
+    }
+
+    /// A missing file is an empty ledger
+    pub fn load(path: impl Into<PathBuf>) -> io::Result<Self> {
+        let path = path.into();
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(error) => return Err(error),
+        };
+
+        let mut answers = BTreeMap::new();
+        for (number, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid = || {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "{}:{}: expected `<day> <part> <answer>`, got `{line}`",
+                        path.display(),
+                        number + 1
+                    ),
+                )
+            };
+            let mut fields = line.splitn(3, ' ');
+            let day = fields.next().and_then(|day| day.parse().ok());
+            let part = fields.next().and_then(|part| part.parse().ok());
+            let answer = fields.next().map(str::trim);
+            match (day, part, answer) {
+                (Some(day), Some(part), Some(answer)) if !answer.is_empty() => {
+                    answers.insert((day, part), answer.to_owned());
+                }
+                _ => return Err(invalid()),
+            }
+        }
+
+        Ok(Ledger { path, answers })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn record(&mut self, day: u8, part: Part, answer: impl Into<String>) {
+        self.answers.insert((day, part), answer.into());
+    }
+
+    /// In order of day and part
+    pub fn iter(&self) -> impl Iterator<Item = (u8, Part, &str)> {
+        self.answers
+            .iter()
+            .map(|(&(day, part), answer)| (day, part, answer.as_str()))
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let mut content = String::from("# day part answer\n");
+        for (day, part, answer) in self.iter() {
+            content.push_str(&format!("{day} {part} {answer}\n"));
+        }
+
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&self.path, content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let path = std::env::temp_dir().join(format!("aoc-ledger-{}.txt", std::process::id()));
+        let mut ledger = Ledger::load(&path).unwrap();
+        assert_eq!(ledger.iter().count(), 0);
+
+        ledger.record(7, Part::Two, "5905");
+        ledger.record(1, Part::One, "142");
+        ledger.save().unwrap();
+
+        let ledger = Ledger::load(&path).unwrap();
+        assert_eq!(ledger.get(1, Part::One), Some("142"));
+        assert_eq!(ledger.get(7, Part::Two), Some("5905"));
+        assert_eq!(ledger.get(7, Part::One), None);
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "# day part answer\n1 1 142\n7 2 5905\n"
+        );
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn beside_the_input() {
+        let root = std::env::temp_dir().join(format!("aoc-ledger-dirs-{}", std::process::id()));
+        let (workspace, user) = (root.join("workspace"), root.join("user"));
+        fs::create_dir_all(&workspace).unwrap();
+        fs::create_dir_all(&user).unwrap();
+        fs::write(workspace.join("1.txt"), "1abc2\n").unwrap();
+        fs::write(user.join("2.txt"), "Game 1: 3 blue\n").unwrap();
+        let inputs = Inputs::with_dirs([&workspace, &user]);
+
+        let mut ledger = Ledger::for_day(&inputs, 1).unwrap();
+        ledger.record(1, Part::One, "12");
+        ledger.save().unwrap();
+        let mut ledger = Ledger::for_day(&inputs, 2).unwrap();
+        ledger.record(2, Part::One, "1");
+        ledger.save().unwrap();
+
+        assert_eq!(
+            Ledger::for_day(&inputs, 1).unwrap().path(),
+            workspace.join(Ledger::FILE_NAME)
+        );
+        assert_eq!(Ledger::for_day(&inputs, 1).unwrap().get(2, Part::One), None);
+        assert_eq!(
+            Ledger::for_day(&inputs, 2).unwrap().get(2, Part::One),
+            Some("1")
+        );
+        assert_eq!(Ledger::for_day(&inputs, 2).unwrap().get(1, Part::One), None);
+        // not fetched yet, so it goes where the input will be cached
+        assert_eq!(
+            Ledger::for_day(&inputs, 3).unwrap().path(),
+            user.join(Ledger::FILE_NAME)
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn invalid_line() {
+        let path = std::env::temp_dir().join(format!("aoc-ledger-bad-{}.txt", std::process::id()));
+        fs::write(&path, "1 1 142\n1 3 4\n").unwrap();
+
+        let error = Ledger::load(&path).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(error.to_string().contains(":2:"));
+
+        fs::remove_file(path).unwrap();
+    }
+}
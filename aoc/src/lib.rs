@@ -0,0 +1,45 @@
+pub mod bench;
+pub mod client;
+pub mod ledger;
+pub mod readme;
+pub mod scaffold;
+
+use std::{
+    any::Any,
+    panic::{catch_unwind, AssertUnwindSafe},
+};
+
+use aoc23::Registry;
+
+/// Every day of the calendar that has a crate in `days/`.
+pub fn registry() -> Registry {
+    let mut registry = Registry::default();
+    registry
+        .register::<_1::Day>()
+        .register::<_2::Day>()
+        .register::<_3::Day>()
+        .register::<_4::Day>()
+        .register::<_5::Day>()
+        .register::<_6::Day>()
+        .register::<_7::Day>()
+        .register::<_8::Day>()
+        .register::<_9::Day>()
+        .register::<_10::Day>();
+    registry
+}
+
+/// Runs `f`, turning its error or panic into a message
+pub fn unwind<T>(f: impl FnOnce() -> aoc23::Result<T>) -> Result<T, String> {
+    match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(result) => result.map_err(|error| error.to_string()),
+        Err(payload) => Err(format!("panicked: {}", panic_message(payload.as_ref()))),
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("no message")
+}
@@ -0,0 +1,512 @@
+use std::{
+    ops::RangeInclusive,
+    path::PathBuf,
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use aoc::{
+    bench::{self, Baseline, Measurement},
+    client::{self, Client, Fetched, Verdict},
+    ledger::Ledger,
+    readme, scaffold, unwind,
+};
+use aoc23::{input, Inputs, Part, Puzzle};
+use clap::{Parser, Subcommand};
+
+#[derive(Debug, Parser)]
+#[command(about = "Advent of Code 2023")]
+struct Cli {
+    /// Directory with one `<day>.txt` per day [default: $AOC_INPUTS, or ./inputs and the per-user directory]
+    #[arg(long, global = true)]
+    inputs: Option<PathBuf>,
+    /// Talk to this server instead of adventofcode.com
+    #[arg(long, global = true, hide = true)]
+    base_url: Option<String>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Solve one or more days and print their answers
+    Run {
+        /// A single day like `5` or a range like `1..=10`
+        #[arg(value_parser = days, required_unless_present = "all")]
+        days: Option<RangeInclusive<u8>>,
+        /// Every registered day
+        #[arg(long, conflicts_with = "days")]
+        all: bool,
+        /// Read the input from this file instead, `-` for stdin. Only for a single day.
+        #[arg(long, conflicts_with = "all")]
+        input: Option<PathBuf>,
+    },
+    /// Download the input for a day, unless there already is one
+    Fetch {
+        #[arg(value_parser = days)]
+        days: RangeInclusive<u8>,
+    },
+    /// Time parsing and both parts of one or more days over many iterations
+    Bench {
+        #[arg(value_parser = days)]
+        days: RangeInclusive<u8>,
+        /// How often each stage runs at most
+        #[arg(long, default_value_t = 100)]
+        iterations: usize,
+        /// Seconds after which a stage stops early
+        #[arg(long, default_value_t = 5.0)]
+        budget: f64,
+        /// Compare against measurements saved with --save
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// Write the measurements to this file
+        #[arg(long)]
+        save: Option<PathBuf>,
+    },
+    /// Create the crate for a new day and register it with the runner
+    New {
+        day: u8,
+        /// Title of the puzzle, fetched from its page if left out
+        #[arg(long)]
+        name: Option<String>,
+    },
+    /// Solve a part and send the answer, accepted answers are kept in the `answers.txt` beside the input
+    Submit { day: u8, part: Part },
+    /// Rewrite the README's table from the solutions' titles, their implemented parts and the accepted answers
+    Readme {
+        /// Only report whether the table is out of date
+        #[arg(long)]
+        check: bool,
+    },
+}
+
+/// Parses `5`, `1..10` and `1..=10`
+fn days(s: &str) -> Result<RangeInclusive<u8>, String> {
+    let number = |s: &str| {
+        s.trim()
+            .parse::<u8>()
+            .map_err(|error| format!("`{s}` isn't a day: {error}"))
+    };
+
+    let days = if let Some((start, end)) = s.split_once("..=") {
+        number(start)?..=number(end)?
+    } else if let Some((start, end)) = s.split_once("..") {
+        let end = number(end)?;
+        if end == 0 {
+            return Err(format!("`{s}` doesn't contain any days"));
+        }
+        number(start)?..=end - 1
+    } else {
+        let day = number(s)?;
+        day..=day
+    };
+
+    if days.is_empty() {
+        Err(format!("`{s}` doesn't contain any days"))
+    } else {
+        Ok(days)
+    }
+}
+
+struct Row {
+    day: u8,
+    part: Part,
+    /// What went wrong if the part failed
+    answer: Result<Option<String>, String>,
+    elapsed: Duration,
+}
+
+impl Row {
+    fn answer(&self) -> &str {
+        match &self.answer {
+            Ok(Some(answer)) => answer,
+            Ok(None) => "unsolved",
+            Err(_) => "failed",
+        }
+    }
+}
+
+/// Runs `f`, with a panic turned into an error like any other
+/// Both parts and the [`Puzzle::notes`] on the input.
+/// A part that fails or panics only fails its own row.
+fn solve(puzzle: &dyn Puzzle, input: &str) -> Result<([Row; 2], Vec<String>), String> {
+    let parsed = unwind(|| puzzle.parse(input))?;
+
+    let rows = Part::BOTH.map(|part| {
+        let start = Instant::now();
+        let answer = unwind(|| puzzle.solve(part, parsed.as_ref()));
+        Row {
+            day: puzzle.day(),
+            part,
+            answer,
+            elapsed: start.elapsed(),
+        }
+    });
+    let notes = unwind(|| Ok(puzzle.notes(parsed.as_ref())))
+        .unwrap_or_else(|error| vec![format!("notes {error}")]);
+    Ok((rows, notes))
+}
+
+fn print(rows: &[Row]) {
+    let width = rows
+        .iter()
+        .map(|row| row.answer().len())
+        .chain(["answer".len()])
+        .max()
+        .unwrap_or_default();
+
+    println!("day | part | {:width$} | elapsed", "answer");
+    println!("----|------|-{:-<width$}-|--------", "");
+    for row in rows {
+        println!(
+            "{:>3} | {:>4} | {:width$} | {:?}",
+            row.day,
+            row.part,
+            row.answer(),
+            row.elapsed,
+        );
+    }
+}
+
+fn print_bench(measurements: &[Measurement], baseline: Option<&Baseline>) {
+    println!(
+        "day | stage |        min |     median |       mean |    std dev | samples | vs baseline"
+    );
+    println!(
+        "----|-------|------------|------------|------------|------------|---------|------------"
+    );
+    for Measurement { day, stage, stats } in measurements {
+        let versus = baseline
+            .and_then(|baseline| baseline.get(*day, *stage))
+            .map(|baseline| format!("{:+.1}%", bench::change(baseline, stats) * 100.0))
+            .unwrap_or_default();
+        println!(
+            "{day:>3} | {:>5} | {:>10} | {:>10} | {:>10} | {:>10} | {:>7} | {versus}",
+            stage.to_string(),
+            format!("{:.2?}", stats.min),
+            format!("{:.2?}", stats.median),
+            format!("{:.2?}", stats.mean),
+            format!("{:.2?}", stats.std_dev),
+            stats.samples,
+        );
+    }
+}
+
+fn client(base_url: Option<String>) -> Result<Client, client::ClientError> {
+    let client = Client::new(client::session()?);
+    Ok(match base_url {
+        Some(base) => client.with_base(base),
+        None => client,
+    })
+}
+
+fn submit(
+    registry: &aoc23::Registry,
+    inputs: &Inputs,
+    base_url: Option<String>,
+    day: u8,
+    part: Part,
+) -> aoc23::Result<()> {
+    let puzzle = registry
+        .get(day)
+        .ok_or_else(|| format!("no solution for day {day}"))?;
+    let mut ledger = Ledger::for_day(inputs, day)?;
+
+    let parsed = puzzle.parse(&inputs.load(day)?)?;
+    let answer = puzzle
+        .solve(part, parsed.as_ref())?
+        .ok_or_else(|| format!("part {part} of day {day} isn't solved yet"))?;
+
+    match ledger.get(day, part) {
+        Some(accepted) if accepted == answer => {
+            println!("day {day} part {part}: {answer} has already been accepted");
+            return Ok(());
+        }
+        Some(accepted) => {
+            return Err(format!(
+                "day {day} part {part}: {accepted} has already been accepted, but the solution now says {answer}"
+            )
+            .into())
+        }
+        None => {}
+    }
+
+    let verdict = client(base_url)?.submit(day, part, &answer)?;
+    println!("day {day} part {part}: {answer} was {verdict}");
+    if verdict == Verdict::Accepted {
+        ledger.record(day, part, answer);
+        ledger.save()?;
+    }
+
+    Ok(())
+}
+
+/// Whether the README had to change
+fn update_readme(registry: &aoc23::Registry, inputs: &Inputs, check: bool) -> aoc23::Result<bool> {
+    let path = scaffold::workspace().join("README.md");
+    let current = std::fs::read_to_string(&path)?;
+    let generated = readme::generate(&current, registry, |day| Ledger::for_day(inputs, day))?
+        .ok_or_else(|| {
+            format!(
+                "couldn't find the Day/Name/Stars table in {}",
+                path.display()
+            )
+        })?;
+
+    let changed = generated != current;
+    if changed && !check {
+        std::fs::write(&path, generated)?;
+    }
+    Ok(changed)
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let registry = aoc::registry();
+    let inputs = cli.inputs.map_or_else(Inputs::from_env, Inputs::new);
+
+    match cli.command {
+        Command::Run { days, all, input } => {
+            let puzzles: Vec<_> = if all {
+                registry.iter().collect()
+            } else {
+                let days = days.expect("clap to require either days or --all");
+                let puzzles: Vec<_> = days.clone().filter_map(|day| registry.get(day)).collect();
+                if puzzles.is_empty() {
+                    eprintln!("no solutions for days {days:?}");
+                    return ExitCode::FAILURE;
+                }
+                puzzles
+            };
+
+            if input.is_some() && puzzles.len() > 1 {
+                eprintln!("--input can only be used with a single day");
+                return ExitCode::FAILURE;
+            }
+
+            let mut status = ExitCode::SUCCESS;
+            let mut rows = Vec::new();
+            for puzzle in puzzles {
+                let day = puzzle.day();
+                let solved = match &input {
+                    Some(path) => input::read(path),
+                    None => inputs.load(day),
+                }
+                .map_err(|error| error.to_string())
+                .and_then(|input| {
+                    solve(puzzle, &input)
+                        .map_err(|error| format!("couldn't parse the input: {error}"))
+                });
+                match solved {
+                    Ok((solved, notes)) => {
+                        for note in notes {
+                            eprintln!("day {day}: {note}");
+                        }
+                        for row in &solved {
+                            if let Err(error) = &row.answer {
+                                eprintln!("day {day} part {}: {error}", row.part);
+                                status = ExitCode::FAILURE;
+                            }
+                        }
+                        rows.extend(solved);
+                    }
+                    Err(error) => {
+                        eprintln!("day {day}: {error}");
+                        status = ExitCode::FAILURE;
+                    }
+                }
+            }
+
+            if !rows.is_empty() {
+                print(&rows);
+            }
+            status
+        }
+        Command::Fetch { days } => {
+            let client = match client(cli.base_url) {
+                Ok(client) => client,
+                Err(error) => {
+                    eprintln!("{error}");
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let mut status = ExitCode::SUCCESS;
+            for day in days {
+                match client.fetch(&inputs, day) {
+                    Ok(Fetched::Cached(path)) => {
+                        println!("day {day}: already have {}", path.display())
+                    }
+                    Ok(Fetched::Downloaded(path)) => {
+                        println!("day {day}: saved to {}", path.display())
+                    }
+                    Err(error) => {
+                        eprintln!("day {day}: {error}");
+                        status = ExitCode::FAILURE;
+                    }
+                }
+            }
+            status
+        }
+        Command::Bench {
+            days,
+            iterations,
+            budget,
+            baseline,
+            save,
+        } => {
+            let baseline = match baseline.as_deref().map(Baseline::load).transpose() {
+                Ok(baseline) => baseline,
+                Err(error) => {
+                    eprintln!("couldn't read the baseline: {error}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            let budget = Duration::from_secs_f64(budget);
+
+            let mut status = ExitCode::SUCCESS;
+            let mut measurements = Vec::new();
+            for day in days {
+                let Some(puzzle) = registry.get(day) else {
+                    continue;
+                };
+                let measured = inputs
+                    .load(day)
+                    .map_err(|error| error.to_string())
+                    .and_then(|input| {
+                        bench::bench(puzzle, &input, iterations, budget)
+                            .map_err(|error| format!("couldn't parse the input: {error}"))
+                    });
+                match measured {
+                    Ok(measured) => {
+                        for (part, error) in measured.failures {
+                            eprintln!("day {day} part {part}: {error}");
+                            status = ExitCode::FAILURE;
+                        }
+                        measurements.extend(measured.measurements);
+                    }
+                    Err(error) => {
+                        eprintln!("day {day}: {error}");
+                        status = ExitCode::FAILURE;
+                    }
+                }
+            }
+
+            if !measurements.is_empty() {
+                print_bench(&measurements, baseline.as_ref());
+            }
+            if let Some(path) = save {
+                if let Err(error) = Baseline::save(&path, &measurements) {
+                    eprintln!("couldn't save the measurements: {error}");
+                    status = ExitCode::FAILURE;
+                }
+            }
+            status
+        }
+        Command::New { day, name } => {
+            let name = match name {
+                Some(name) => Ok(name),
+                None => {
+                    let client = Client::anonymous();
+                    match cli.base_url {
+                        Some(base) => client.with_base(base),
+                        None => client,
+                    }
+                    .title(day)
+                }
+            };
+            let created = name
+                .map_err(|error| format!("couldn't get the puzzle's title, pass --name: {error}"))
+                .and_then(|name| {
+                    scaffold::new_day(&scaffold::workspace(), day, &name)
+                        .map_err(|error| error.to_string())
+                });
+            match created {
+                Ok(dir) => {
+                    println!("created {}", dir.display());
+                    ExitCode::SUCCESS
+                }
+                Err(error) => {
+                    eprintln!("{error}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::Readme { check } => match update_readme(&registry, &inputs, check) {
+            Ok(false) => {
+                println!("README.md is up to date");
+                ExitCode::SUCCESS
+            }
+            Ok(true) if check => {
+                eprintln!("README.md is out of date, run `aoc readme`");
+                ExitCode::FAILURE
+            }
+            Ok(true) => {
+                println!("updated README.md");
+                ExitCode::SUCCESS
+            }
+            Err(error) => {
+                eprintln!("{error}");
+                ExitCode::FAILURE
+            }
+        },
+        Command::Submit { day, part } => {
+            match submit(&registry, &inputs, cli.base_url, day, part) {
+                Ok(()) => ExitCode::SUCCESS,
+                Err(error) => {
+                    eprintln!("{error}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc23::{Registry, Solution};
+
+    use super::*;
+
+    /// Part one and the notes panic, part two works
+    struct Flaky;
+
+    impl Solution for Flaky {
+        const DAY: u8 = 26;
+        const TITLE: &'static str = "Flaky";
+
+        type Parsed = u32;
+        type One = u32;
+        type Two = u32;
+
+        fn parse(input: &str) -> aoc23::Result<Self::Parsed> {
+            Ok(input.parse()?)
+        }
+
+        fn part_one(_: &Self::Parsed) -> aoc23::Result<Self::One> {
+            panic!("out of cheese")
+        }
+
+        fn part_two(parsed: &Self::Parsed) -> aoc23::Result<Self::Two> {
+            Ok(*parsed + 1)
+        }
+
+        fn notes(_: &Self::Parsed) -> Vec<String> {
+            panic!("no notes either")
+        }
+    }
+
+    #[test]
+    fn panics_only_fail_their_part() {
+        let mut registry = Registry::default();
+        registry.register::<Flaky>();
+        let puzzle = registry.get(26).unwrap();
+
+        let ([one, two], notes) = solve(puzzle, "41").unwrap();
+        assert_eq!(one.answer, Err("panicked: out of cheese".to_string()));
+        assert_eq!(one.answer(), "failed");
+        assert_eq!(two.answer, Ok(Some("42".to_string())));
+        assert_eq!(notes, ["notes panicked: no notes either"]);
+        assert!(solve(puzzle, "forty-one").is_err());
+    }
+}
@@ -0,0 +1,269 @@
+//! The Day/Name/Stars table in the README.
+
+use std::{fmt::Display, io};
+
+use aoc23::{Part, Puzzle, Registry};
+
+use crate::ledger::Ledger;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    pub day: u8,
+    pub name: String,
+    /// Implemented parts
+    pub stars: u8,
+    /// Whether every implemented part has an accepted answer in the ledger, shown as a `*` otherwise
+    pub verified: bool,
+}
+
+impl Row {
+    /// What the code and the ledger say about a day
+    pub fn new(puzzle: &dyn Puzzle, ledger: &Ledger) -> Self {
+        let implemented: Vec<_> = Part::BOTH
+            .into_iter()
+            .filter(|&part| puzzle.implemented(part))
+            .collect();
+
+        Row {
+            day: puzzle.day(),
+            name: puzzle.title().to_owned(),
+            stars: implemented.len() as u8,
+            verified: implemented
+                .iter()
+                .all(|&part| ledger.get(puzzle.day(), part).is_some()),
+        }
+    }
+}
+
+impl Display for Row {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Row {
+            day,
+            name,
+            stars,
+            verified,
+        } = self;
+        write!(
+            f,
+            "|{:^28}|{:^70}|{:^7}|",
+            format!("[{day}](days/_{day}/src/lib.rs)"),
+            format!(
+                "[{}](https://adventofcode.com/2023/day/{day})",
+                name.replace('|', "\\|")
+            ),
+            format!("{stars}{}", if *verified { "" } else { "*" })
+        )
+    }
+}
+
+impl Row {
+    /// Reads a line like `|  [1](days/_1/src/lib.rs)  |  [Trebuchet?!](https://…/day/1)  |   2   |`
+    pub fn parse(line: &str) -> Option<Self> {
+        let cells = cells(line.trim().strip_prefix('|')?.strip_suffix('|')?);
+        let [day, name, stars] = cells[..] else {
+            return None;
+        };
+        let link_text = |cell: &str| {
+            let cell = cell.trim().strip_prefix('[')?;
+            cell.split_once("](")
+                .map(|(text, _)| text.replace("\\|", "|"))
+        };
+
+        Some(Row {
+            day: link_text(day)?.parse().ok()?,
+            name: link_text(name)?,
+            stars: stars.trim().trim_end_matches('*').parse().ok()?,
+            verified: !stars.trim().ends_with('*'),
+        })
+    }
+}
+
+/// Splits a row at every `|` that isn't escaped as `\|`
+fn cells(row: &str) -> Vec<&str> {
+    let mut cells = Vec::new();
+    let mut start = 0;
+    let mut escaped = false;
+    for (offset, c) in row.char_indices() {
+        if c == '|' && !escaped {
+            cells.push(&row[start..offset]);
+            start = offset + 1;
+        }
+        escaped = c == '\\' && !escaped;
+    }
+    cells.push(&row[start..]);
+    cells
+}
+
+/// The rows of the table, in the order they appear
+pub fn rows(readme: &str) -> Vec<Row> {
+    readme.lines().filter_map(Row::parse).collect()
+}
+
+/// Puts `rows` in place of the current ones, sorted by day.
+/// The table has to exist already, rows are inserted right below its header.
+pub fn replace_rows(readme: &str, mut rows: Vec<Row>) -> Option<String> {
+    rows.sort_by_key(|row| row.day);
+    rows.dedup_by_key(|row| row.day);
+
+    let lines: Vec<_> = readme.lines().collect();
+    let separator = lines
+        .iter()
+        .position(|line| line.starts_with("|---") && line.matches('|').count() == 4)?;
+
+    let mut replaced: Vec<String> = lines[..=separator]
+        .iter()
+        .map(|&line| line.to_owned())
+        .collect();
+    replaced.extend(rows.iter().map(Row::to_string));
+    replaced.extend(
+        lines[separator + 1..]
+            .iter()
+            .skip_while(|line| Row::parse(line).is_some())
+            .map(|&line| line.to_owned()),
+    );
+
+    let mut updated = replaced.join("\n");
+    if readme.ends_with('\n') {
+        updated.push('\n');
+    }
+    Some(updated)
+}
+
+/// The table as it should be for every registered day, checked against the ledger `ledger` gives for each day.
+/// `None` without a table in `readme`.
+pub fn generate(
+    readme: &str,
+    registry: &Registry,
+    ledger: impl Fn(u8) -> io::Result<Ledger>,
+) -> io::Result<Option<String>> {
+    let rows = registry
+        .iter()
+        .map(|puzzle| Ok(Row::new(puzzle, &ledger(puzzle.day())?)))
+        .collect::<io::Result<_>>()?;
+    Ok(replace_rows(readme, rows))
+}
+
+/// Adds a row for `row.day`, or replaces the one that's already there
+pub fn upsert(readme: &str, row: Row) -> Option<String> {
+    let mut rows: Vec<_> = rows(readme)
+        .into_iter()
+        .filter(|existing| existing.day != row.day)
+        .collect();
+    rows.push(row);
+    replace_rows(readme, rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const README: &str = "# Title
+
+|            Day             |                               Name                                   | Stars |
+|---------------------------:|:--------------------------------------------------------------------:|:-----:|
+|  [1](days/_1/src/lib.rs)  |          [Trebuchet?!](https://adventofcode.com/2023/day/1)          |  2*   |
+| [10](days/_10/src/lib.rs) |           [Pipe Maze](https://adventofcode.com/2023/day/10)          |   0   |
+
+## Running
+";
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            rows(README),
+            [
+                Row {
+                    day: 1,
+                    name: "Trebuchet?!".to_owned(),
+                    stars: 2,
+                    verified: false
+                },
+                Row {
+                    day: 10,
+                    name: "Pipe Maze".to_owned(),
+                    stars: 0,
+                    verified: true
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn roundtrip() {
+        let row = Row {
+            day: 5,
+            name: "If You Give A Seed A Fertilizer".to_owned(),
+            stars: 2,
+            verified: false,
+        };
+        assert_eq!(Row::parse(&row.to_string()), Some(row));
+
+        let row = Row {
+            day: 12,
+            name: "Hot | Springs".to_owned(),
+            stars: 0,
+            verified: true,
+        };
+        assert!(row.to_string().contains("[Hot \\| Springs]"));
+        assert_eq!(Row::parse(&row.to_string()), Some(row));
+    }
+
+    #[test]
+    fn insert_sorted() {
+        let readme = upsert(
+            README,
+            Row {
+                day: 2,
+                name: "Cube Conundrum".to_owned(),
+                stars: 0,
+                verified: true,
+            },
+        )
+        .unwrap();
+
+        let days: Vec<_> = rows(&readme).iter().map(|row| row.day).collect();
+        assert_eq!(days, [1, 2, 10]);
+        assert!(readme.starts_with("# Title\n\n|            Day"));
+        assert!(readme.ends_with("|\n\n## Running\n"));
+    }
+
+    #[test]
+    fn replace() {
+        let readme = upsert(
+            README,
+            Row {
+                day: 10,
+                name: "Pipe Maze".to_owned(),
+                stars: 1,
+                verified: true,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(rows(&readme).len(), 2);
+        assert_eq!(rows(&readme)[1].stars, 1);
+    }
+
+    #[test]
+    fn from_registry_and_ledger() {
+        let registry = crate::registry();
+        let path = std::env::temp_dir().join(format!("aoc-readme-{}.txt", std::process::id()));
+        let mut ledger = Ledger::load(&path).unwrap();
+        ledger.record(3, Part::One, "4361");
+        ledger.record(3, Part::Two, "467835");
+        ledger.save().unwrap();
+
+        let readme = generate(README, &registry, |_| Ledger::load(&path))
+            .unwrap()
+            .unwrap();
+        std::fs::remove_file(path).unwrap();
+        let rows = rows(&readme);
+
+        assert_eq!(rows.len(), registry.iter().count());
+        assert_eq!(rows[0].name, "Trebuchet?!");
+        assert!(!rows[0].verified);
+        assert_eq!(rows[2].name, "Gear Ratios");
+        assert!(rows[2].stars > 0);
+        assert!(rows[2].verified);
+    }
+}
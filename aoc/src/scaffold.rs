@@ -0,0 +1,238 @@
+//! Sets up the crate for a new day and wires it into the runner.
+
+use std::{
+    fs, io,
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+};
+
+use crate::readme::{self, Row};
+
+const CARGO_TOML: &str = include_str!("../template/Cargo.toml");
+const LIB_RS: &str = include_str!("../template/lib.rs");
+
+/// The days of an Advent of Code
+const DAYS: RangeInclusive<u8> = 1..=25;
+
+/// The root of this workspace
+pub fn workspace() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the runner to live inside the workspace")
+        .to_owned()
+}
+
+/// Creates `days/_<day>` in `root`, registers it with the runner and adds it to the README.
+///
+/// # Errors
+/// If `day` isn't one of the 25 days, the day already exists, or one of the files the day gets added to doesn't look as expected
+pub fn new_day(root: &Path, day: u8, name: &str) -> io::Result<PathBuf> {
+    if !DAYS.contains(&day) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "there's no day {day}, only {} to {}",
+                DAYS.start(),
+                DAYS.end()
+            ),
+        ));
+    }
+
+    let dir = root.join("days").join(format!("_{day}"));
+    if dir.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", dir.display()),
+        ));
+    }
+
+    // Check everything that needs updating before creating anything
+    let manifest_path = root.join("aoc").join("Cargo.toml");
+    let manifest = add_dependency(&fs::read_to_string(&manifest_path)?, day)
+        .ok_or_else(|| unexpected(&manifest_path, "the `_<day> = { path = … }` dependencies"))?;
+    let registry_path = root.join("aoc").join("src").join("lib.rs");
+    let registry = add_registration(&fs::read_to_string(&registry_path)?, day)
+        .ok_or_else(|| unexpected(&registry_path, "the `.register::<_<day>::Day>()` chain"))?;
+    let readme_path = root.join("README.md");
+    let readme = readme::upsert(
+        &fs::read_to_string(&readme_path)?,
+        Row {
+            day,
+            name: name.to_owned(),
+            stars: 0,
+            verified: true,
+        },
+    )
+    .ok_or_else(|| unexpected(&readme_path, "the Day/Name/Stars table"))?;
+
+    let fill = |template: &str| {
+        template
+            .replace("{day}", &day.to_string())
+            .replace("{title}", &format!("{name:?}"))
+    };
+    fs::create_dir_all(dir.join("src"))?;
+    fs::create_dir_all(dir.join("examples"))?;
+    fs::write(dir.join("Cargo.toml"), fill(CARGO_TOML))?;
+    fs::write(dir.join("src").join("lib.rs"), fill(LIB_RS))?;
+    fs::write(dir.join("examples").join("example.txt"), "")?;
+    fs::write(dir.join("examples").join("example.answers"), "")?;
+
+    fs::write(manifest_path, manifest)?;
+    fs::write(registry_path, registry)?;
+    fs::write(readme_path, readme)?;
+
+    Ok(dir)
+}
+
+fn unexpected(path: &Path, missing: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("couldn't find {missing} in {}", path.display()),
+    )
+}
+
+/// The day of a line like `_5 = { path = "../days/_5" }`
+fn dependency(line: &str) -> Option<u8> {
+    let (name, rest) = line.split_once(" = ")?;
+    rest.contains("../days/_")
+        .then(|| name.strip_prefix('_')?.parse().ok())
+        .flatten()
+}
+
+fn add_dependency(manifest: &str, day: u8) -> Option<String> {
+    let lines: Vec<_> = manifest.lines().collect();
+    let last = lines.iter().rposition(|line| dependency(line).is_some())?;
+    let position = lines
+        .iter()
+        .position(|line| dependency(line).is_some_and(|existing| existing > day))
+        .unwrap_or(last + 1);
+
+    let mut lines: Vec<String> = lines.iter().map(|&line| line.to_owned()).collect();
+    lines.insert(
+        position,
+        format!("_{day} = {{ path = \"../days/_{day}\" }}"),
+    );
+    Some(lines.join("\n") + "\n")
+}
+
+/// The day of a line like `        .register::<_5::Day>()`
+fn registration(line: &str) -> Option<u8> {
+    line.trim()
+        .strip_prefix(".register::<_")?
+        .split_once("::Day>()")?
+        .0
+        .parse()
+        .ok()
+}
+
+fn add_registration(lib: &str, day: u8) -> Option<String> {
+    let lines: Vec<_> = lib.lines().collect();
+    let first = lines.iter().position(|line| registration(line).is_some())?;
+    let count = lines[first..]
+        .iter()
+        .take_while(|line| registration(line).is_some())
+        .count();
+    let indent = &lines[first][..lines[first].len() - lines[first].trim_start().len()];
+
+    let mut days: Vec<_> = lines[first..first + count]
+        .iter()
+        .filter_map(|line| registration(line))
+        .collect();
+    days.push(day);
+    days.sort_unstable();
+    days.dedup();
+
+    let mut chain: Vec<_> = days
+        .iter()
+        .map(|day| format!("{indent}.register::<_{day}::Day>()"))
+        .collect();
+    if let Some(last) = chain.last_mut() {
+        last.push(';');
+    }
+
+    let mut updated: Vec<String> = lines[..first].iter().map(|&line| line.to_owned()).collect();
+    updated.extend(chain);
+    updated.extend(lines[first + count..].iter().map(|&line| line.to_owned()));
+    Some(updated.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dependencies() {
+        let manifest = "[dependencies]\naoc23 = { path = \"..\" }\n_1 = { path = \"../days/_1\" }\n_10 = { path = \"../days/_10\" }\nclap = \"4\"\n";
+
+        assert_eq!(
+            add_dependency(manifest, 2).unwrap(),
+            "[dependencies]\naoc23 = { path = \"..\" }\n_1 = { path = \"../days/_1\" }\n_2 = { path = \"../days/_2\" }\n_10 = { path = \"../days/_10\" }\nclap = \"4\"\n"
+        );
+        assert_eq!(
+            add_dependency(manifest, 11).unwrap(),
+            "[dependencies]\naoc23 = { path = \"..\" }\n_1 = { path = \"../days/_1\" }\n_10 = { path = \"../days/_10\" }\n_11 = { path = \"../days/_11\" }\nclap = \"4\"\n"
+        );
+    }
+
+    #[test]
+    fn registrations() {
+        let lib = "fn registry() {\n    registry\n        .register::<_1::Day>()\n        .register::<_10::Day>();\n    registry\n}\n";
+
+        assert_eq!(
+            add_registration(lib, 11).unwrap(),
+            "fn registry() {\n    registry\n        .register::<_1::Day>()\n        .register::<_10::Day>()\n        .register::<_11::Day>();\n    registry\n}\n"
+        );
+    }
+
+    #[test]
+    fn new_day_in_copy_of_workspace() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("aoc").join("src")).unwrap();
+        for file in ["aoc/Cargo.toml", "aoc/src/lib.rs", "README.md"] {
+            fs::copy(workspace().join(file), root.join(file)).unwrap();
+        }
+
+        let dir = new_day(&root, 11, "Cosmic Expansion").unwrap();
+
+        let lib = fs::read_to_string(dir.join("src").join("lib.rs")).unwrap();
+        assert!(lib.contains("const DAY: u8 = 11;"));
+        assert!(lib.contains("const TITLE: &'static str = \"Cosmic Expansion\";"));
+        assert!(dir.join("examples").join("example.answers").is_file());
+        assert!(fs::read_to_string(root.join("aoc/Cargo.toml"))
+            .unwrap()
+            .contains("_11 = { path = \"../days/_11\" }"));
+        assert!(fs::read_to_string(root.join("aoc/src/lib.rs"))
+            .unwrap()
+            .contains(".register::<_11::Day>();"));
+        assert!(
+            readme::rows(&fs::read_to_string(root.join("README.md")).unwrap())
+                .iter()
+                .any(|row| row.day == 11 && row.name == "Cosmic Expansion")
+        );
+
+        assert_eq!(
+            new_day(&root, 11, "Cosmic Expansion").unwrap_err().kind(),
+            io::ErrorKind::AlreadyExists
+        );
+        for day in [0, 26] {
+            assert_eq!(
+                new_day(&root, day, "Nope").unwrap_err().kind(),
+                io::ErrorKind::InvalidInput
+            );
+            assert!(!root.join("days").join(format!("_{day}")).exists());
+        }
+
+        let dir = new_day(&root, 12, "Hot | Springs").unwrap();
+        assert!(fs::read_to_string(dir.join("src").join("lib.rs"))
+            .unwrap()
+            .contains("const TITLE: &'static str = \"Hot | Springs\";"));
+        assert!(
+            readme::rows(&fs::read_to_string(root.join("README.md")).unwrap())
+                .iter()
+                .any(|row| row.day == 12 && row.name == "Hot | Springs")
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,34 @@
+use aoc23::{Solution, Unsolved};
+
+pub struct Day;
+
+impl Solution for Day {
+    const DAY: u8 = {day};
+    const TITLE: &'static str = {title};
+
+    type Parsed = String;
+    type One = Unsolved;
+    type Two = Unsolved;
+
+    fn parse(input: &str) -> aoc23::Result<Self::Parsed> {
+        Ok(input.to_owned())
+    }
+
+    fn part_one(_: &Self::Parsed) -> aoc23::Result<Self::One> {
+        Ok(Unsolved)
+    }
+
+    fn part_two(_: &Self::Parsed) -> aoc23::Result<Self::Two> {
+        Ok(Unsolved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        aoc23::examples::check::<Day>(concat!(env!("CARGO_MANIFEST_DIR"), "/examples"));
+    }
+}
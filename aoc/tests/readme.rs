@@ -0,0 +1,23 @@
+//! The README's table has to match what the solutions and the ledger say.
+
+use std::fs;
+
+use aoc::{ledger::Ledger, readme, scaffold::workspace};
+use aoc23::Inputs;
+
+#[test]
+fn table_is_up_to_date() {
+    let root = workspace();
+    let current = fs::read_to_string(root.join("README.md")).unwrap();
+    let inputs = Inputs::new(root.join("inputs"));
+
+    let generated = readme::generate(&current, &aoc::registry(), |day| {
+        Ledger::for_day(&inputs, day)
+    })
+    .unwrap()
+    .unwrap();
+    assert!(
+        generated == current,
+        "README.md is out of date, run `cargo run -p aoc -- readme`"
+    );
+}
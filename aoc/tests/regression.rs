@@ -0,0 +1,73 @@
+//! Runs every registered day against its input and compares with the answers in the ledger beside it.
+
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use aoc::ledger::Ledger;
+use aoc23::{Inputs, Part};
+
+#[test]
+fn recorded_answers() {
+    let inputs = Inputs::from_env();
+    let registry = aoc::registry();
+
+    let mut regressions = Vec::new();
+    let mut checked = 0;
+    for puzzle in registry.iter() {
+        let day = puzzle.day();
+        let input = match inputs.load(day) {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("skipping day {day}: {error}");
+                continue;
+            }
+        };
+
+        let ledger = Ledger::for_day(&inputs, day).expect("the ledger to be readable");
+        let expected: Vec<_> = Part::BOTH
+            .into_iter()
+            .filter_map(|part| match ledger.get(day, part) {
+                Some(answer) => Some((part, answer)),
+                None => {
+                    eprintln!(
+                        "skipping day {day} part {part}: no answer in {}",
+                        ledger.path().display()
+                    );
+                    None
+                }
+            })
+            .collect();
+        if expected.is_empty() {
+            continue;
+        }
+
+        let parsed = match puzzle.parse(&input) {
+            Ok(parsed) => parsed,
+            Err(error) => {
+                regressions.push(format!("day {day}: couldn't parse the input: {error}"));
+                continue;
+            }
+        };
+
+        for (part, answer) in expected {
+            checked += 1;
+            match catch_unwind(AssertUnwindSafe(|| puzzle.solve(part, parsed.as_ref()))) {
+                Ok(Ok(Some(actual))) if actual == answer => {}
+                Ok(Ok(Some(actual))) => regressions.push(format!(
+                    "day {day} part {part}: expected {answer}, got {actual}"
+                )),
+                Ok(Ok(None)) => regressions.push(format!(
+                    "day {day} part {part}: expected {answer}, but it's unsolved"
+                )),
+                Ok(Err(error)) => regressions.push(format!(
+                    "day {day} part {part}: expected {answer}, but it failed: {error}"
+                )),
+                Err(_) => regressions.push(format!(
+                    "day {day} part {part}: expected {answer}, but it panicked"
+                )),
+            }
+        }
+    }
+
+    eprintln!("checked {checked} answers");
+    assert!(regressions.is_empty(), "{}", regressions.join("\n"));
+}
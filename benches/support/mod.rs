@@ -0,0 +1,44 @@
+//! Shared by the days' benchmarks, which pull it in with
+//! `#[path = "../../../benches/support/mod.rs"] mod support;`
+
+use aoc23::bench::Stats;
+
+/// Prints one line of [`Stats`] per measurement, with the names lined up
+pub fn report<'a>(measurements: impl IntoIterator<Item = (&'a str, Stats)>) {
+    let measurements: Vec<_> = measurements.into_iter().collect();
+    let width = measurements
+        .iter()
+        .map(|(name, _)| name.len())
+        .max()
+        .unwrap_or_default();
+    for (name, stats) in measurements {
+        println!(
+            "{:width$}  median {:.2?}, min {:.2?}, std dev {:.2?} over {} runs",
+            format!("{name}:"),
+            stats.median,
+            stats.min,
+            stats.std_dev,
+            stats.samples,
+            width = width + 1
+        );
+    }
+}
+
+/// Same numbers on every run, so generated inputs don't need a random number generator
+#[derive(Debug, Clone)]
+pub struct Lcg(u64);
+
+impl Lcg {
+    pub fn new(seed: u64) -> Self {
+        Lcg(seed)
+    }
+
+    /// Somewhere in `0..n`
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (self.0 >> 33) % n
+    }
+}
@@ -0,0 +1,120 @@
+use aoc23::Solution;
+use rayon::prelude::*;
+
+pub struct Day;
+
+impl Solution for Day {
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Trebuchet?!";
+
+    type Parsed = Vec<Line>;
+    type One = u32;
+    type Two = u32;
+
+    fn parse(input: &str) -> aoc23::Result<Self::Parsed> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| {
+                let digits = digits(line);
+                if digits.is_empty() {
+                    Err(format!("line {} doesn't have any digits: `{line}`", index + 1).into())
+                } else {
+                    Ok(Line {
+                        number: index + 1,
+                        digits,
+                    })
+                }
+            })
+            .collect()
+    }
+
+    fn part_one(lines: &Self::Parsed) -> aoc23::Result<Self::One> {
+        calibrate(lines, false)
+    }
+
+    fn part_two(lines: &Self::Parsed) -> aoc23::Result<Self::Two> {
+        calibrate(lines, true)
+    }
+}
+
+const SPELLED: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// A digit somewhere in a line, either as a digit or written out
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Digit {
+    value: u32,
+    spelled: bool,
+}
+
+#[derive(Debug)]
+pub struct Line {
+    /// Counted from 1, for error messages
+    number: usize,
+    /// Left to right, spelled out ones may overlap like in `eightwo`
+    digits: Vec<Digit>,
+}
+
+fn digits(line: &str) -> Vec<Digit> {
+    line.char_indices()
+        .filter_map(|(offset, c)| {
+            if let Some(value) = c.to_digit(10).filter(|&value| value > 0) {
+                return Some(Digit {
+                    value,
+                    spelled: false,
+                });
+            }
+            SPELLED
+                .iter()
+                .position(|word| line[offset..].starts_with(word))
+                .map(|index| Digit {
+                    value: index as u32 + 1,
+                    spelled: true,
+                })
+        })
+        .collect()
+}
+
+/// Sums the first and last digit of every line.
+/// With `spelled` digits may also be written out as words, without it a line needs at least one digit.
+fn calibrate(lines: &[Line], spelled: bool) -> aoc23::Result<u32> {
+    lines
+        .par_iter()
+        .map(|line| {
+            let mut digits = line.digits.iter().filter(|digit| spelled || !digit.spelled);
+            let first = digits.next().ok_or_else(|| {
+                format!(
+                    "line {} only has spelled out digits, part one needs real ones",
+                    line.number
+                )
+            })?;
+            let last = digits.next_back().unwrap_or(first);
+            Ok(first.value * 10 + last.value)
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        aoc23::examples::check::<Day>(concat!(env!("CARGO_MANIFEST_DIR"), "/examples"));
+    }
+
+    #[test]
+    fn without_digits() {
+        let lines = Day::parse(include_str!("../examples/part_two.txt")).unwrap();
+        assert_eq!(
+            Day::part_one(&lines).unwrap_err().to_string(),
+            "line 2 only has spelled out digits, part one needs real ones"
+        );
+        assert_eq!(
+            Day::parse("1abc2\nabc\n").unwrap_err().to_string(),
+            "line 2 doesn't have any digits: `abc`"
+        );
+    }
+}
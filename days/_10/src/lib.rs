@@ -0,0 +1,179 @@
+use aoc23::{
+    grid::{Grid, Heading, Point},
+    Solution, Unsolved,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Vertical,
+    Horizontal,
+    NorthEast,
+    NorthWest,
+    SouthWest,
+    SouthEast,
+    Ground,
+    Start,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        use Tile::*;
+        Ok(match value {
+            '|' => Vertical,
+            '-' => Horizontal,
+            'L' => NorthEast,
+            'J' => NorthWest,
+            '7' => SouthWest,
+            'F' => SouthEast,
+            '.' => Ground,
+            'S' => Start,
+            _ => return Err(format!("`{value}` isn't a tile")),
+        })
+    }
+}
+
+impl Tile {
+    /// Where a pipe leads when entered while moving into `heading`
+    fn turn(self, heading: Heading) -> Option<Heading> {
+        use Heading::*;
+        use Tile::*;
+        match (self, heading) {
+            (Vertical, North | South) | (Horizontal, East | West) => Some(heading),
+            (NorthEast, South) | (SouthEast, North) => Some(East),
+            (NorthEast, West) | (NorthWest, East) => Some(North),
+            (NorthWest, South) | (SouthWest, North) => Some(West),
+            (SouthWest, East) | (SouthEast, West) => Some(South),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Map {
+    tiles: Grid<Tile>,
+    start: Point,
+}
+
+impl TryFrom<&str> for Map {
+    type Error = Box<dyn std::error::Error + Send + Sync>;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let tiles: Grid<Tile> = Grid::parse(input, Tile::try_from)?;
+        let start = tiles
+            .position(|&tile| tile == Tile::Start)
+            .ok_or("there's no `S` to start from")?;
+
+        Ok(Map { tiles, start })
+    }
+}
+
+impl Map {
+    /// Where the `S` is
+    pub fn start(&self) -> Point {
+        self.start
+    }
+
+    /// Walks the pipes from `position` until they end or lead off the map
+    pub fn follow(&self, mut position: Point, mut heading: Heading) -> (u32, Point) {
+        let mut steps = 0;
+        loop {
+            let Some(next) = self.tiles.step(position, heading, 1) else {
+                return (steps, position);
+            };
+            let Some(turned) = self.tiles[next].turn(heading) else {
+                return (steps, next);
+            };
+            position = next;
+            heading = turned;
+            steps += 1;
+        }
+    }
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Pipe Maze";
+
+    type Parsed = Map;
+    type One = Unsolved;
+    type Two = Unsolved;
+
+    fn parse(input: &str) -> aoc23::Result<Self::Parsed> {
+        Map::try_from(input)
+    }
+
+    fn part_one(_: &Self::Parsed) -> aoc23::Result<Self::One> {
+        Ok(Unsolved)
+    }
+
+    fn part_two(_: &Self::Parsed) -> aoc23::Result<Self::Two> {
+        Ok(Unsolved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        aoc23::examples::check::<Day>(concat!(env!("CARGO_MANIFEST_DIR"), "/examples"));
+    }
+
+    /// The real input isn't checked in, tests that need it are skipped without it
+    fn input() -> Option<Map> {
+        match aoc23::Inputs::from_env().load(10) {
+            Ok(input) => Some(Map::try_from(input.as_str()).unwrap()),
+            Err(error) => {
+                eprintln!("skipping: {error}");
+                None
+            }
+        }
+    }
+
+    const EXAMPLE: &str = include_str!("../examples/square.txt");
+
+    #[test]
+    fn example_start() {
+        let map = Map::try_from(EXAMPLE).unwrap();
+        assert_eq!(map.start(), (1, 1));
+        assert_eq!(map.tiles[(2, 1)], Tile::Horizontal);
+        assert_eq!(map.follow((1, 1), Heading::East), (7, (1, 1)));
+    }
+
+    #[test]
+    fn make_map() {
+        let _ = input();
+    }
+
+    #[test]
+    fn index_map() {
+        let Some(map) = input() else { return };
+        assert_eq!(map.tiles[(0, 0)], Tile::Vertical);
+        assert_eq!(map.tiles[(1, 0)], Tile::Horizontal);
+        assert_eq!(map.tiles[(map.tiles.width() - 1, 0)], Tile::NorthWest);
+        assert_eq!(map.tiles[(0, 1)], Tile::Vertical);
+        assert_eq!(map.tiles[(0, 2)], Tile::NorthEast);
+        assert_eq!(map.tiles[(0, 3)], Tile::Vertical);
+        assert_eq!(map.tiles[(1, 1)], Tile::SouthWest);
+    }
+
+    #[test]
+    fn index_map2() {
+        let Some(map) = input() else { return };
+        assert_eq!(map.tiles[(map.start.0, map.start.1 - 1)], Tile::NorthWest);
+        assert_eq!(map.tiles[(map.start.0 + 1, map.start.1)], Tile::NorthEast);
+        assert_eq!(map.tiles[(map.start.0, map.start.1 + 1)], Tile::NorthEast);
+        assert_eq!(map.tiles[(map.start.0 - 1, map.start.1)], Tile::SouthEast);
+    }
+
+    #[test]
+    fn start() {
+        let Some(map) = input() else { return };
+        assert_eq!(map.tiles[map.start], Tile::Start)
+    }
+}
@@ -1,4 +1,6 @@
-use std::{cmp::max, ops, u16};
+use std::{cmp::max, ops};
+
+use aoc23::Solution;
 
 use nom::{
     branch::alt,
@@ -11,10 +13,8 @@ use nom::{
 };
 use rayon::prelude::*;
 
-const INPUT: &str = include_str!("input.txt");
-
 #[derive(Debug, Clone)]
-struct Game {
+pub struct Game {
     id: u8,
     rounds: Vec<Round>,
 }
@@ -111,32 +111,58 @@ fn game(input: &str) -> IResult<&str, Game> {
     Ok((input, Game { id, rounds }))
 }
 
-fn main() {
-    let games = INPUT
-        .par_lines()
-        .map(game)
-        .map(Finish::finish)
-        .filter_map(Result::ok)
-        .map(|(_, game)| game);
-
-    let games2 = games.clone();
-
-    //a
-    let sum = games
-        .filter_map(|game| {
-            game.rounds
-                .iter()
-                .all(|round| round.red <= 12 && round.green <= 13 && round.blue <= 14)
-                .then_some(game.id as u16)
-        })
-        .sum::<u16>();
-    println!("{sum}");
-
-    // b
-    let sum = games2
-        .map(|game| game.min_set_of_cubes())
-        .map(|round| round.pow())
-        .sum::<u32>();
-
-    println!("{sum}");
+pub struct Day;
+
+impl Solution for Day {
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Cube Conundrum";
+
+    type Parsed = Vec<Game>;
+    type One = u16;
+    type Two = u32;
+
+    fn parse(input: &str) -> aoc23::Result<Self::Parsed> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| match game(line).finish() {
+                Ok(("", game)) => Ok(game),
+                _ => Err(format!(
+                    "line {}: expected `Game <id>: <count> <color>, …; …`, got `{line}`",
+                    index + 1
+                )
+                .into()),
+            })
+            .collect()
+    }
+
+    fn part_one(games: &Self::Parsed) -> aoc23::Result<Self::One> {
+        Ok(games
+            .par_iter()
+            .filter_map(|game| {
+                game.rounds
+                    .iter()
+                    .all(|round| round.red <= 12 && round.green <= 13 && round.blue <= 14)
+                    .then_some(game.id as u16)
+            })
+            .sum())
+    }
+
+    fn part_two(games: &Self::Parsed) -> aoc23::Result<Self::Two> {
+        Ok(games
+            .par_iter()
+            .map(|game| game.min_set_of_cubes())
+            .map(|round| round.pow())
+            .sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        aoc23::examples::check::<Day>(concat!(env!("CARGO_MANIFEST_DIR"), "/examples"));
+    }
 }
@@ -0,0 +1,239 @@
+use std::{char::TryFromCharError, collections::BTreeMap};
+
+use aoc23::{
+    grid::{Grid, GridError, Point},
+    Solution,
+};
+
+#[derive(Debug)]
+enum State {
+    Number(usize),
+    Part(usize),
+    Junk,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Token {
+    Digit,
+    Symbol,
+    Gear,
+    Junk,
+}
+
+impl From<u8> for Token {
+    fn from(value: u8) -> Self {
+        match value {
+            b'*' => Self::Gear,
+            b'.' => Self::Junk,
+            _ if value.is_ascii_digit() => Self::Digit,
+            _ => Self::Symbol,
+        }
+    }
+}
+
+pub struct Schematic {
+    grid: Grid<u8>,
+}
+
+impl TryFrom<&str> for Schematic {
+    type Error = GridError<TryFromCharError>;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        Ok(Schematic {
+            grid: input.parse()?,
+        })
+    }
+}
+
+fn vicinity(schematic: &Schematic, position: Point) -> impl Iterator<Item = (Point, Token)> + '_ {
+    schematic
+        .grid
+        .neighbours_with_diagonals(position)
+        .map(|position| (position, Token::from(schematic.grid[position])))
+}
+
+/// Whether any symbol is next to `position`, remembering the gears among them
+fn inspect(schematic: &Schematic, position: Point, gears: &mut Vec<Point>) -> bool {
+    let mut symbol = false;
+    for (position, token) in vicinity(schematic, position) {
+        match token {
+            Token::Gear => {
+                if !gears.contains(&position) {
+                    gears.push(position);
+                }
+                symbol = true;
+            }
+            Token::Symbol => symbol = true,
+            Token::Digit | Token::Junk => {}
+        }
+    }
+    symbol
+}
+
+fn to_number(s: &[u8]) -> u64 {
+    s.iter()
+        .map(|&b| char::from(b))
+        .collect::<String>()
+        .parse()
+        .unwrap()
+}
+
+/// A number next to at least one symbol
+#[derive(Debug, PartialEq, Eq)]
+struct PartNumber {
+    value: u64,
+    /// Every `*` next to any of its digits
+    gears: Vec<Point>,
+}
+
+fn part_numbers(schematic: &Schematic) -> Vec<PartNumber> {
+    let mut parts = Vec::new();
+
+    for (y, row) in schematic.grid.rows().enumerate() {
+        let mut state = State::Junk;
+        let mut gears = Vec::new();
+
+        // The end of a row ends any number in it
+        let tokens = row.iter().copied().map(Token::from).chain([Token::Junk]);
+        for (x, token) in tokens.enumerate() {
+            state = match (token, state) {
+                // Inside the gutter
+                (Token::Junk | Token::Symbol | Token::Gear, State::Junk) => State::Junk,
+                // Can promote to part?
+                (Token::Digit, State::Junk) => {
+                    gears.clear();
+                    if inspect(schematic, (x, y), &mut gears) {
+                        State::Part(x)
+                    } else {
+                        State::Number(x)
+                    }
+                }
+                (Token::Digit, State::Number(start)) => {
+                    if inspect(schematic, (x, y), &mut gears) {
+                        State::Part(start)
+                    } else {
+                        State::Number(start)
+                    }
+                }
+                // Inside a part, there might be more gears around
+                (Token::Digit, State::Part(start)) => {
+                    inspect(schematic, (x, y), &mut gears);
+                    State::Part(start)
+                }
+                // A number ends
+                (Token::Junk | Token::Symbol | Token::Gear, State::Number(_)) => State::Junk,
+                // A Part ends
+                (Token::Junk | Token::Symbol | Token::Gear, State::Part(start)) => {
+                    parts.push(PartNumber {
+                        value: to_number(&row[start..x]),
+                        gears: std::mem::take(&mut gears),
+                    });
+                    State::Junk
+                }
+            };
+        }
+    }
+
+    parts
+}
+
+fn a(schematic: &Schematic) -> u64 {
+    part_numbers(schematic).iter().map(|part| part.value).sum()
+}
+
+/// Sum of the products of the two part numbers around every gear that has exactly two
+fn b(schematic: &Schematic) -> u64 {
+    let mut gears: BTreeMap<Point, Vec<u64>> = BTreeMap::new();
+    for part in part_numbers(schematic) {
+        for gear in part.gears {
+            gears.entry(gear).or_default().push(part.value);
+        }
+    }
+
+    gears
+        .values()
+        .filter_map(|parts| match parts[..] {
+            [first, second] => Some(first * second),
+            _ => None,
+        })
+        .sum()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Gear Ratios";
+
+    type Parsed = Schematic;
+    type One = u64;
+    type Two = u64;
+
+    fn parse(input: &str) -> aoc23::Result<Self::Parsed> {
+        Ok(Schematic::try_from(input)?)
+    }
+
+    fn part_one(schematic: &Self::Parsed) -> aoc23::Result<Self::One> {
+        Ok(a(schematic))
+    }
+
+    fn part_two(schematic: &Self::Parsed) -> aoc23::Result<Self::Two> {
+        Ok(b(schematic))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        aoc23::examples::check::<Day>(concat!(env!("CARGO_MANIFEST_DIR"), "/examples"));
+    }
+
+    fn sum(input: &str) -> u64 {
+        a(&Schematic::try_from(input).unwrap())
+    }
+
+    #[test]
+    fn right_edge() {
+        assert_eq!(sum("..12\n...#\n"), 12);
+        assert_eq!(sum("..12\n#...\n"), 0);
+        assert_eq!(sum("#...\n..12"), 0);
+        // A number in the last column still ends with its row
+        assert_eq!(sum("...1\n2#..\n"), 2);
+    }
+
+    #[test]
+    fn gears() {
+        let schematic = Schematic::try_from("12*..\n..3*4\n").unwrap();
+        assert_eq!(
+            part_numbers(&schematic),
+            [
+                PartNumber {
+                    value: 12,
+                    gears: vec![(2, 0)]
+                },
+                PartNumber {
+                    value: 3,
+                    gears: vec![(2, 0), (3, 1)]
+                },
+                PartNumber {
+                    value: 4,
+                    gears: vec![(3, 1)]
+                },
+            ]
+        );
+        assert_eq!(b(&schematic), 12 * 3 + 3 * 4);
+
+        // A gear with three numbers around it doesn't count
+        assert_eq!(b(&Schematic::try_from("1.2\n.*.\n3..").unwrap()), 0);
+    }
+
+    #[test]
+    fn left_edge() {
+        assert_eq!(sum("#...\n12..\n"), 12);
+        assert_eq!(sum("...#\n12..\n"), 0);
+        assert_eq!(sum("12..\n...#"), 0);
+    }
+}
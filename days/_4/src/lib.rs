@@ -0,0 +1,130 @@
+use aoc23::Solution;
+use nom::Finish;
+use rayon::prelude::*;
+use std::{collections::BTreeSet, str::FromStr};
+
+#[derive(Debug)]
+pub struct Game {
+    #[allow(dead_code)]
+    id: u8,
+    winners: BTreeSet<u8>,
+    mine: BTreeSet<u8>,
+}
+
+impl FromStr for Game {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match parse::game(s).finish() {
+            Ok(("", game)) => Ok(game),
+            _ => Err(format!(
+                "expected `Card <id>: <winning numbers> | <numbers you have>`, got `{s}`"
+            )),
+        }
+    }
+}
+
+impl Game {
+    fn matches(&self) -> u8 {
+        self.winners.intersection(&self.mine).count() as u8
+    }
+
+    fn score(&self) -> u16 {
+        let matches = self.matches();
+        if matches == 0 {
+            0
+        } else {
+            1 << (matches - 1)
+        }
+    }
+}
+
+mod parse {
+    use nom::{
+        bytes::complete::tag,
+        character::complete::{char, digit1, space0, space1},
+        combinator::map_res,
+        multi::separated_list1,
+        sequence::{delimited, pair, tuple},
+        IResult,
+    };
+
+    use crate::Game;
+
+    pub fn game(input: &str) -> IResult<&str, Game> {
+        let (input, id) = map_res(
+            delimited(pair(tag("Card"), space0), digit1, char(':')),
+            |s: &str| s.parse::<u8>(),
+        )(input)?;
+        let (input, _) = space1(input)?;
+        let number = || map_res(digit1, str::parse::<u8>);
+        let (input, winners) = separated_list1(space1, number())(input)?;
+        let (input, _) = tuple((space0, char('|'), space0))(input)?;
+        let (input, mine) = separated_list1(space1, number())(input)?;
+
+        let winners = winners.into_iter().collect();
+        let mine = mine.into_iter().collect();
+
+        Ok((input, Game { id, winners, mine }))
+    }
+}
+
+fn a(games: &[Game]) -> u16 {
+    games.par_iter().map(|game| game.score()).sum()
+}
+
+fn b(games: &[Game]) -> u32 {
+    let matches: Vec<_> = games.par_iter().map(|game| game.matches()).collect();
+
+    let mut cards = Vec::from_iter(std::iter::repeat_n(1, matches.len()));
+
+    for index in 0..matches.len() {
+        let matches = matches[index];
+        let instances = cards[index];
+        for count in cards.iter_mut().skip(index + 1).take(matches as usize) {
+            *count += instances;
+        }
+    }
+
+    cards.iter().sum()
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Scratchcards";
+
+    type Parsed = Vec<Game>;
+    type One = u16;
+    type Two = u32;
+
+    fn parse(input: &str) -> aoc23::Result<Self::Parsed> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| {
+                line.parse()
+                    .map_err(|error| format!("line {}: {error}", index + 1).into())
+            })
+            .collect()
+    }
+
+    fn part_one(games: &Self::Parsed) -> aoc23::Result<Self::One> {
+        Ok(a(games))
+    }
+
+    fn part_two(games: &Self::Parsed) -> aoc23::Result<Self::Two> {
+        Ok(b(games))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        aoc23::examples::check::<Day>(concat!(env!("CARGO_MANIFEST_DIR"), "/examples"));
+    }
+}
@@ -0,0 +1,815 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    error::Error,
+    fmt::Display,
+    ops::Range,
+    str::FromStr,
+};
+
+use aoc23::Solution;
+use nom::Finish;
+use rayon::prelude::*;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct Mapping {
+    from: Range<i64>,
+    offset: i64,
+}
+
+impl Mapping {
+    fn new(destination: i64, source: i64, range: i64) -> Self {
+        Mapping {
+            from: source..(source + range),
+            offset: destination - source,
+        }
+    }
+
+    fn try_map(&self, value: i64) -> Option<i64> {
+        if self.from.contains(&value) {
+            Some(value + self.offset)
+        } else {
+            None
+        }
+    }
+
+    /// Where `from` ends up
+    fn to(&self) -> Range<i64> {
+        self.from.start + self.offset..self.from.end + self.offset
+    }
+}
+
+/// A piecewise linear map. The mappings are sorted and don't overlap,
+/// values outside of all of them map to themselves.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Layer {
+    mappings: Vec<Mapping>,
+}
+
+impl Layer {
+    /// Sorts the mappings and merges neighbours that move by the same offset
+    fn new(mut mappings: Vec<Mapping>) -> Self {
+        mappings.retain(|mapping| mapping.offset != 0 && !mapping.from.is_empty());
+        mappings.sort_unstable_by_key(|mapping| mapping.from.start);
+
+        let mut merged: Vec<Mapping> = Vec::with_capacity(mappings.len());
+        for mapping in mappings {
+            match merged.last_mut() {
+                Some(last)
+                    if last.from.end == mapping.from.start && last.offset == mapping.offset =>
+                {
+                    last.from.end = mapping.from.end;
+                }
+                _ => merged.push(mapping),
+            }
+        }
+
+        Layer { mappings: merged }
+    }
+
+    /// A single layer doing what all of `layers` do one after another
+    pub fn compose<'a>(layers: impl IntoIterator<Item = &'a Layer>) -> Self {
+        layers
+            .into_iter()
+            .fold(Layer::default(), |composed, layer| composed.then(layer))
+    }
+
+    pub fn map(&self, value: i64) -> i64 {
+        let index = self
+            .mappings
+            .partition_point(|mapping| mapping.from.end <= value);
+        self.mappings
+            .get(index)
+            .and_then(|mapping| mapping.try_map(value))
+            .unwrap_or(value)
+    }
+
+    /// The mappings with the identity filled in between, covering every value
+    fn pieces(&self) -> Vec<Mapping> {
+        let mut pieces = Vec::with_capacity(self.mappings.len() * 2 + 1);
+        let mut start = i64::MIN;
+        for mapping in &self.mappings {
+            pieces.push(Mapping {
+                from: start..mapping.from.start,
+                offset: 0,
+            });
+            pieces.push(mapping.clone());
+            start = mapping.from.end;
+        }
+        pieces.push(Mapping {
+            from: start..i64::MAX,
+            offset: 0,
+        });
+        pieces.retain(|piece| !piece.from.is_empty());
+        pieces
+    }
+
+    /// The mappings of `self` and the identity between them, cut wherever they overlap `range`
+    fn split(&self, range: Range<i64>) -> Vec<Mapping> {
+        let mut split = Vec::new();
+        let mut start = range.start;
+
+        let first = self
+            .mappings
+            .partition_point(|mapping| mapping.from.end <= start);
+        for mapping in &self.mappings[first..] {
+            if mapping.from.start >= range.end {
+                break;
+            }
+
+            if mapping.from.start > start {
+                split.push(Mapping {
+                    from: start..mapping.from.start,
+                    offset: 0,
+                });
+                start = mapping.from.start;
+            }
+            let end = mapping.from.end.min(range.end);
+            split.push(Mapping {
+                from: start..end,
+                offset: mapping.offset,
+            });
+            start = end;
+        }
+
+        if start < range.end {
+            split.push(Mapping {
+                from: start..range.end,
+                offset: 0,
+            });
+        }
+        split
+    }
+
+    /// Where all of `range` ends up. It gets split wherever a mapping starts or ends,
+    /// the parts between mappings stay where they are.
+    pub fn map_range(&self, range: Range<i64>) -> Vec<Range<i64>> {
+        self.split(range).iter().map(Mapping::to).collect()
+    }
+
+    /// `self` first, then `next`
+    pub fn then(&self, next: &Layer) -> Layer {
+        Layer::new(
+            self.pieces()
+                .into_iter()
+                .flat_map(|piece| {
+                    next.split(piece.to()).into_iter().map(move |part| Mapping {
+                        from: part.from.start - piece.offset..part.from.end - piece.offset,
+                        offset: piece.offset + part.offset,
+                    })
+                })
+                .collect(),
+        )
+    }
+
+    /// Every value that ends up in `range`, sorted and with touching ranges merged.
+    /// Several ranges can end up on the same values, so there's no single way back.
+    ///
+    /// Goes through every piece, [`Inverse::preimage`] is quicker for more than a few lookups.
+    pub fn preimage(&self, range: Range<i64>) -> Vec<Range<i64>> {
+        merged(
+            self.pieces()
+                .iter()
+                .filter_map(|piece| {
+                    let to = piece.to();
+                    let (start, end) = (to.start.max(range.start), to.end.min(range.end));
+                    (start < end).then(|| start - piece.offset..end - piece.offset)
+                })
+                .collect(),
+        )
+    }
+
+    /// The way back from where the values end up, see [`Inverse`]
+    pub fn invert(&self) -> Inverse {
+        let pieces = self.pieces();
+        let mut cuts: Vec<_> = pieces
+            .iter()
+            .flat_map(|piece| {
+                let to = piece.to();
+                [to.start, to.end]
+            })
+            .collect();
+        cuts.sort_unstable();
+        cuts.dedup();
+
+        let mut segments: Vec<_> = cuts
+            .windows(2)
+            .map(|cut| (cut[0]..cut[1], Vec::new()))
+            .collect();
+        for piece in &pieces {
+            let to = piece.to();
+            let first = cuts.partition_point(|&cut| cut < to.start);
+            let last = cuts.partition_point(|&cut| cut < to.end);
+            for (_, offsets) in &mut segments[first..last] {
+                offsets.push(piece.offset);
+            }
+        }
+        segments.retain(|(_, offsets)| !offsets.is_empty());
+
+        Inverse { segments }
+    }
+}
+
+/// A [`Layer`] from the other end. Where the pieces end up is cut wherever one of them
+/// starts or ends, every segment knows the offsets of all the pieces that land on it.
+/// That's more than one where the layer isn't one-to-one.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Inverse {
+    /// Sorted and not overlapping
+    segments: Vec<(Range<i64>, Vec<i64>)>,
+}
+
+impl Inverse {
+    /// Same as [`Layer::preimage`], but only looks at the segments overlapping `range`
+    pub fn preimage(&self, range: Range<i64>) -> Vec<Range<i64>> {
+        let first = self
+            .segments
+            .partition_point(|(segment, _)| segment.end <= range.start);
+        merged(
+            self.segments[first..]
+                .iter()
+                .take_while(|(segment, _)| segment.start < range.end)
+                .flat_map(|(segment, offsets)| {
+                    let (start, end) = (segment.start.max(range.start), segment.end.min(range.end));
+                    offsets
+                        .iter()
+                        .map(move |offset| start - offset..end - offset)
+                })
+                .filter(|range| !range.is_empty())
+                .collect(),
+        )
+    }
+}
+
+/// Sorted, with overlapping and touching ranges merged
+fn merged(mut ranges: Vec<Range<i64>>) -> Vec<Range<i64>> {
+    ranges.sort_unstable_by_key(|range| range.start);
+
+    let mut merged: Vec<Range<i64>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if last.end >= range.start => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+/// One line per mapping, like `50..98 +2`
+impl Display for Layer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for Mapping { from, offset } in &self.mappings {
+            writeln!(f, "{from:?} {offset:+}")?;
+        }
+        Ok(())
+    }
+}
+
+/// One `<source>-to-<destination> map:` section
+#[derive(Debug)]
+struct Conversion {
+    source: String,
+    destination: String,
+    layer: Layer,
+}
+
+#[derive(Debug)]
+pub struct Almanac {
+    seeds: Vec<i64>,
+    /// Every category once, each section converts one into the next
+    categories: Vec<String>,
+    /// Sorted so that `conversions[i]` goes from `categories[i]` to `categories[i + 1]`
+    conversions: Vec<Conversion>,
+}
+
+impl Almanac {
+    /// Puts the sections in the order of the categories they convert between
+    ///
+    /// # Errors
+    /// If the categories don't form a single chain
+    fn new(seeds: Vec<i64>, conversions: Vec<Conversion>) -> Result<Self, AlmanacError> {
+        let mut by_source = BTreeMap::new();
+        let mut destinations = BTreeSet::new();
+        for (index, conversion) in conversions.iter().enumerate() {
+            if by_source.insert(conversion.source.clone(), index).is_some() {
+                return Err(AlmanacError::Branches(conversion.source.clone()));
+            }
+            if !destinations.insert(conversion.destination.clone()) {
+                return Err(AlmanacError::Branches(conversion.destination.clone()));
+            }
+        }
+
+        let mut starts = by_source
+            .keys()
+            .filter(|source| !destinations.contains(*source));
+        let (Some(start), None) = (starts.next(), starts.next()) else {
+            return Err(AlmanacError::NotAChain);
+        };
+
+        let mut categories = vec![start.clone()];
+        let mut sections: Vec<_> = conversions.into_iter().map(Some).collect();
+        let mut conversions = Vec::with_capacity(sections.len());
+        while let Some(&index) = by_source.get(categories.last().unwrap()) {
+            // Each section is only taken once, so a cycle ends the chain
+            let Some(conversion) = sections[index].take() else {
+                break;
+            };
+            categories.push(conversion.destination.clone());
+            conversions.push(conversion);
+        }
+        if conversions.len() != sections.len() {
+            return Err(AlmanacError::NotAChain);
+        }
+
+        Ok(Almanac {
+            seeds,
+            categories,
+            conversions,
+        })
+    }
+
+    /// The seeds as a plain list, like part one reads them
+    pub fn seeds(&self) -> &[i64] {
+        &self.seeds
+    }
+
+    /// The seeds as pairs of start and length, like part two reads them
+    ///
+    /// # Errors
+    /// If there's an odd number of seeds
+    pub fn seed_ranges(&self) -> Result<Vec<Range<i64>>, AlmanacError> {
+        if !self.seeds.len().is_multiple_of(2) {
+            return Err(AlmanacError::OddSeeds(self.seeds.len()));
+        }
+        Ok(self
+            .seeds
+            .chunks_exact(2)
+            .map(|pair| pair[0]..pair[0] + pair[1])
+            .collect())
+    }
+
+    /// From the first category to the last
+    pub fn categories(&self) -> impl Iterator<Item = &str> {
+        self.categories.iter().map(String::as_str)
+    }
+
+    fn position(&self, category: &str) -> Result<usize, AlmanacError> {
+        self.categories
+            .iter()
+            .position(|other| other == category)
+            .ok_or_else(|| AlmanacError::UnknownCategory(category.to_owned()))
+    }
+
+    /// Everything from one category to a later one in one layer
+    ///
+    /// # Errors
+    /// If either category doesn't exist, or `to` comes before `from`
+    pub fn converter(&self, from: &str, to: &str) -> Result<Layer, AlmanacError> {
+        let (start, end) = (self.position(from)?, self.position(to)?);
+        if start > end {
+            return Err(AlmanacError::Backwards {
+                from: from.to_owned(),
+                to: to.to_owned(),
+            });
+        }
+        Ok(Layer::compose(
+            self.conversions[start..end]
+                .iter()
+                .map(|conversion| &conversion.layer),
+        ))
+    }
+
+    /// Where `values` of `from` end up in `to`, in either direction.
+    /// Like `almanac.convert("seed", "humidity", 79..80)` or `almanac.convert("location", "soil", 35..36)`,
+    /// going backwards gives every value that leads to one of `values`, see [`Inverse::preimage`].
+    ///
+    /// # Errors
+    /// If either category doesn't exist
+    pub fn convert(
+        &self,
+        from: &str,
+        to: &str,
+        values: Range<i64>,
+    ) -> Result<Vec<Range<i64>>, AlmanacError> {
+        match self.converter(from, to) {
+            Ok(layer) => Ok(layer.map_range(values)),
+            Err(AlmanacError::Backwards { .. }) => {
+                Ok(self.converter(to, from)?.invert().preimage(values))
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    /// All layers in one
+    pub fn seed_to_location(&self) -> Layer {
+        Layer::compose(self.conversions.iter().map(|conversion| &conversion.layer))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum AlmanacError {
+    /// The line that couldn't be read, counted from 1
+    Syntax {
+        line: usize,
+        text: String,
+    },
+    /// More than one section converts from or to this category
+    Branches(String),
+    /// The sections don't lead from one category through all others
+    NotAChain,
+    UnknownCategory(String),
+    /// Asked for a [`Almanac::converter`] from a category to an earlier one
+    Backwards {
+        from: String,
+        to: String,
+    },
+    /// Part two reads the seeds in pairs, this many don't pair up
+    OddSeeds(usize),
+    /// None of the seed ranges contains a seed
+    NoSeeds,
+}
+
+impl Display for AlmanacError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AlmanacError::Syntax { line, text } => {
+                write!(f, "line {line}: can't make sense of `{text}`")
+            }
+            AlmanacError::Branches(category) => {
+                write!(f, "`{category}` is converted from or to more than once")
+            }
+            AlmanacError::NotAChain => {
+                f.write_str("the maps don't lead from one category through all the others")
+            }
+            AlmanacError::UnknownCategory(category) => write!(f, "there's no `{category}`"),
+            AlmanacError::Backwards { from, to } => {
+                write!(f, "`{to}` comes before `{from}`, there's no converter back")
+            }
+            AlmanacError::OddSeeds(count) => {
+                write!(f, "{count} seeds don't pair up into starts and lengths")
+            }
+            AlmanacError::NoSeeds => f.write_str("none of the seed ranges contains a seed"),
+        }
+    }
+}
+
+impl Error for AlmanacError {}
+
+impl FromStr for Almanac {
+    type Err = AlmanacError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match parse::almanac(s).finish() {
+            Ok((remaining, (seeds, conversions))) if remaining.trim().is_empty() => {
+                Almanac::new(seeds, conversions)
+            }
+            Ok((remaining, _)) => Err(AlmanacError::syntax(s, remaining)),
+            Err(error) => Err(AlmanacError::syntax(s, error.input)),
+        }
+    }
+}
+
+impl AlmanacError {
+    /// Points at the line of `input` that `rest` starts on
+    fn syntax(input: &str, rest: &str) -> Self {
+        let offset = input.len() - rest.trim_start().len();
+        let start = input[..offset].rfind('\n').map_or(0, |newline| newline + 1);
+        AlmanacError::Syntax {
+            line: input[..start].lines().count() + 1,
+            text: input[start..].lines().next().unwrap_or_default().to_owned(),
+        }
+    }
+}
+
+mod parse {
+    use nom::{
+        branch::alt,
+        bytes::complete::tag,
+        character::complete::{alpha1, char, digit1, line_ending, multispace1},
+        combinator::{eof, map, map_res, peek},
+        multi::{many1, separated_list1},
+        sequence::{preceded, separated_pair, terminated},
+        IResult,
+    };
+
+    use crate::{Conversion, Layer, Mapping};
+
+    /// The whole first line
+    fn seeds(input: &str) -> IResult<&str, Vec<i64>> {
+        let (input, _) = tag("seeds: ")(input)?;
+        terminated(
+            separated_list1(char(' '), map_res(digit1, str::parse)),
+            peek(alt((line_ending, eof))),
+        )(input)
+    }
+
+    fn mapping(input: &str) -> IResult<&str, Mapping> {
+        let (input, _) = multispace1(input)?;
+        let (input, destination) = map_res(digit1, str::parse)(input)?;
+        let (input, source) = map_res(preceded(char(' '), digit1), str::parse)(input)?;
+        let (input, range) = map_res(preceded(char(' '), digit1), str::parse)(input)?;
+
+        Ok((input, Mapping::new(destination, source, range)))
+    }
+
+    /// `seed-to-soil map:`
+    fn header(input: &str) -> IResult<&str, (String, String)> {
+        let (input, _) = multispace1(input)?;
+        map(
+            terminated(separated_pair(alpha1, tag("-to-"), alpha1), tag(" map:")),
+            |(source, destination): (&str, &str)| (source.to_owned(), destination.to_owned()),
+        )(input)
+    }
+
+    fn conversion(input: &str) -> IResult<&str, Conversion> {
+        let (input, (source, destination)) = header(input)?;
+        let (input, mappings) = many1(mapping)(input)?;
+
+        Ok((
+            input,
+            Conversion {
+                source,
+                destination,
+                layer: Layer::new(mappings),
+            },
+        ))
+    }
+
+    /// The seeds and the sections in the order they are written down
+    pub fn almanac(input: &str) -> IResult<&str, (Vec<i64>, Vec<Conversion>)> {
+        let (input, seeds) = seeds(input)?;
+        let (input, conversions) = many1(conversion)(input)?;
+
+        Ok((input, (seeds, conversions)))
+    }
+}
+
+fn a(almanac: &Almanac) -> i64 {
+    let seed_to_location = almanac.seed_to_location();
+    almanac
+        .seeds()
+        .par_iter()
+        .map(|&seed| seed_to_location.map(seed))
+        .min()
+        .unwrap()
+}
+
+/// Lowest location of any seed in the ranges, pushing them through the layers whole
+fn b(almanac: &Almanac) -> Result<i64, AlmanacError> {
+    let seed_to_location = almanac.seed_to_location();
+    almanac
+        .seed_ranges()?
+        .into_iter()
+        .flat_map(|seeds| seed_to_location.map_range(seeds))
+        .filter(|range| !range.is_empty())
+        .map(|range| range.start)
+        .min()
+        .ok_or(AlmanacError::NoSeeds)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "If You Give A Seed A Fertilizer";
+
+    type Parsed = Almanac;
+    type One = i64;
+    type Two = i64;
+
+    fn parse(input: &str) -> aoc23::Result<Self::Parsed> {
+        Ok(Almanac::from_str(input)?)
+    }
+
+    fn part_one(almanac: &Self::Parsed) -> aoc23::Result<Self::One> {
+        Ok(a(almanac))
+    }
+
+    fn part_two(almanac: &Self::Parsed) -> aoc23::Result<Self::Two> {
+        Ok(b(almanac)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        aoc23::examples::check::<Day>(concat!(env!("CARGO_MANIFEST_DIR"), "/examples"));
+    }
+
+    /// Tries every location from 0 up until one leads back to a seed
+    fn brute_force(almanac: &Almanac) -> i64 {
+        let seed_to_location = almanac.seed_to_location();
+        let seed_ranges = almanac.seed_ranges().unwrap();
+        (0..i64::MAX)
+            .find(|&location| {
+                seed_to_location
+                    .preimage(location..location + 1)
+                    .iter()
+                    .flat_map(|seeds| seeds.clone())
+                    .any(|seed| seed_ranges.iter().any(|range| range.contains(&seed)))
+            })
+            .unwrap()
+    }
+
+    const EXAMPLE: &str = include_str!("../examples/example.txt");
+
+    #[test]
+    fn ranges_agree_with_brute_force() {
+        let almanac = Day::parse(EXAMPLE).unwrap();
+        assert_eq!(b(&almanac).unwrap(), brute_force(&almanac));
+    }
+
+    #[test]
+    fn map_range() {
+        let layer = Layer::new(vec![Mapping::new(50, 98, 2), Mapping::new(52, 50, 48)]);
+
+        assert_eq!(layer.map_range(0..10), vec![(0..10)]);
+        assert_eq!(layer.map_range(45..55), [45..50, 52..57]);
+        assert_eq!(layer.map_range(97..102), [99..100, 50..52, 100..102]);
+        assert_eq!(layer.map_range(60..70), vec![(62..72)]);
+    }
+
+    #[test]
+    fn parse() {
+        let almanac = Day::parse(EXAMPLE).unwrap();
+
+        assert_eq!(almanac.seeds(), [79, 14, 55, 13]);
+        assert_eq!(almanac.seed_ranges().unwrap(), [79..93, 55..68]);
+        assert_eq!(
+            almanac.categories().collect::<Vec<_>>(),
+            [
+                "seed",
+                "soil",
+                "fertilizer",
+                "water",
+                "light",
+                "temperature",
+                "humidity",
+                "location"
+            ]
+        );
+    }
+
+    #[test]
+    fn convert() {
+        let almanac = Day::parse(EXAMPLE).unwrap();
+
+        assert_eq!(
+            almanac.convert("seed", "humidity", 79..80).unwrap(),
+            vec![(78..79)]
+        );
+        assert_eq!(
+            almanac.convert("seed", "location", 13..14).unwrap(),
+            vec![(35..36)]
+        );
+        assert_eq!(
+            almanac.convert("location", "soil", 35..36).unwrap(),
+            vec![(13..14)]
+        );
+        assert_eq!(
+            almanac.convert("water", "water", 7..9).unwrap(),
+            vec![(7..9)]
+        );
+        assert_eq!(
+            almanac.convert("seed", "dirt", 1..2),
+            Err(AlmanacError::UnknownCategory("dirt".to_owned()))
+        );
+        assert_eq!(
+            almanac.converter("location", "seed").unwrap_err(),
+            AlmanacError::Backwards {
+                from: "location".to_owned(),
+                to: "seed".to_owned()
+            }
+        );
+
+        // Soil 52 and 53 come from seeds 50 and 51, but also from seeds 52 and 53 themselves
+        let almanac: Almanac = "seeds: 1\n\nseed-to-soil map:\n52 50 2\n".parse().unwrap();
+        assert_eq!(
+            almanac.convert("soil", "seed", 50..54).unwrap(),
+            vec![(50..54)]
+        );
+        assert_eq!(almanac.convert("soil", "seed", 50..52), Ok(vec![]));
+    }
+
+    #[test]
+    fn chain() {
+        let almanac: Almanac = "seeds: 1\n\nb-to-c map:\n0 1 1\n\na-to-b map:\n5 0 2\n"
+            .parse()
+            .unwrap();
+        assert_eq!(almanac.categories().collect::<Vec<_>>(), ["a", "b", "c"]);
+        assert_eq!(almanac.convert("a", "c", 0..1).unwrap(), vec![(5..6)]);
+
+        let invalid = |input: &str| input.parse::<Almanac>().unwrap_err();
+        assert_eq!(
+            invalid("seeds: 1\n\na-to-b map:\n0 1 1\n\na-to-c map:\n0 1 1\n"),
+            AlmanacError::Branches("a".to_owned())
+        );
+        assert_eq!(
+            invalid("seeds: 1\n\na-to-b map:\n0 1 1\n\nc-to-d map:\n0 1 1\n"),
+            AlmanacError::NotAChain
+        );
+        assert_eq!(
+            invalid("seeds: 1\n\na-to-b map:\n0 1 1\n\nb-to-c map:\n0 1 1\n\nd-to-a map:\n0 1 1\n\nc-to-d map:\n0 1 1\n"),
+            AlmanacError::NotAChain
+        );
+    }
+
+    #[test]
+    fn compose() {
+        let almanac = Day::parse(EXAMPLE).unwrap();
+        let composed = almanac.seed_to_location();
+
+        for seed in 0..120 {
+            let stepwise = almanac
+                .conversions
+                .iter()
+                .fold(seed, |value, conversion| conversion.layer.map(value));
+            assert_eq!(composed.map(seed), stepwise, "seed {seed}");
+        }
+        assert_eq!(composed.map(79), 82);
+        // Normalized, so nothing to merge anymore
+        assert_eq!(Layer::new(composed.mappings.clone()), composed);
+    }
+
+    #[test]
+    fn preimage() {
+        let almanac = Day::parse(EXAMPLE).unwrap();
+        let composed = almanac.seed_to_location();
+
+        for seed in 0..120 {
+            let location = composed.map(seed);
+            let preimage = composed.preimage(location..location + 1);
+            assert!(
+                preimage.iter().any(|seeds| seeds.contains(&seed)),
+                "seed {seed}"
+            );
+            for seeds in preimage {
+                assert!(seeds.clone().all(|other| composed.map(other) == location));
+            }
+        }
+
+        let squashed = Layer::new(vec![Mapping::new(0, 10, 5)]);
+        assert_eq!(squashed.preimage(0..5), [0..5, 10..15]);
+        assert_eq!(squashed.preimage(3..12), [3..10, 13..15]);
+    }
+
+    #[test]
+    fn invert() {
+        let almanac = Day::parse(EXAMPLE).unwrap();
+        let squashed = Layer::new(vec![Mapping::new(0, 10, 5)]);
+
+        for layer in [almanac.seed_to_location(), squashed.clone()] {
+            let inverse = layer.invert();
+            for start in -10..120 {
+                for length in [0, 1, 3, 17, 50] {
+                    let range = start..start + length;
+                    assert_eq!(
+                        inverse.preimage(range.clone()),
+                        layer.preimage(range.clone()),
+                        "{range:?}"
+                    );
+                }
+            }
+        }
+        assert_eq!(squashed.invert().preimage(3..12), [3..10, 13..15]);
+        assert_eq!(Layer::default().invert().preimage(-4..4), vec![(-4..4)]);
+    }
+
+    #[test]
+    fn seeds() {
+        let almanac: Almanac = "seeds: 1 2 3\n\na-to-b map:\n0 1 1\n".parse().unwrap();
+        assert_eq!(Day::part_one(&almanac).unwrap(), 0);
+        assert_eq!(
+            Day::part_two(&almanac).unwrap_err().to_string(),
+            "3 seeds don't pair up into starts and lengths"
+        );
+
+        let almanac: Almanac = "seeds: 1 0\n\na-to-b map:\n0 1 1\n".parse().unwrap();
+        assert_eq!(b(&almanac), Err(AlmanacError::NoSeeds));
+    }
+
+    #[test]
+    fn syntax() {
+        let invalid = |input: &str| input.parse::<Almanac>().unwrap_err();
+        assert_eq!(
+            invalid("seeds: 1 x\n\na-to-b map:\n0 1 1\n"),
+            AlmanacError::Syntax {
+                line: 1,
+                text: "seeds: 1 x".to_owned()
+            }
+        );
+        assert_eq!(
+            invalid("seeds: 1\n\na-to-b map:\n0 1 1\n0 1 x\n\nb-to-c map:\n0 1 1\n"),
+            AlmanacError::Syntax {
+                line: 5,
+                text: "0 1 x".to_owned()
+            }
+        );
+        assert_eq!(
+            invalid("seeds: 1\n\na-to-b map:\n0 1 1\n\nleftovers\n").to_string(),
+            "line 6: can't make sense of `leftovers`"
+        );
+    }
+}
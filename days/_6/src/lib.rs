@@ -0,0 +1,207 @@
+use std::{error::Error, num::ParseIntError};
+
+use aoc23::Solution;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{digit1, line_ending, multispace0, space1},
+    combinator::all_consuming,
+    multi::separated_list1,
+    sequence::{pair, preceded},
+    Finish, IResult,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Run {
+    duration: u64,
+    record: u64,
+}
+
+/// The `Time:` and `Distance:` lines
+#[derive(Debug, PartialEq, Eq)]
+pub struct Sheet {
+    /// One per column
+    runs: Vec<Run>,
+    /// The columns read as a single race, with the spaces between them ignored
+    joined: Run,
+}
+
+fn numbers<'a>(label: &'static str) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<&'a str>> {
+    preceded(pair(tag(label), space1), separated_list1(space1, digit1))
+}
+
+fn sheet(input: &str) -> IResult<&str, (Vec<&str>, Vec<&str>)> {
+    let (input, durations) = numbers("Time:")(input)?;
+    let (input, _) = line_ending(input)?;
+    let (input, records) = numbers("Distance:")(input)?;
+    let (input, _) = multispace0(input)?;
+    Ok((input, (durations, records)))
+}
+
+impl TryFrom<&str> for Sheet {
+    type Error = Box<dyn Error + Send + Sync>;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let (_, (durations, records)) = all_consuming(sheet)(input)
+            .finish()
+            .map_err(|error| format!("{:?} at `{}`", error.code, error.input))?;
+        if durations.len() != records.len() {
+            return Err(
+                format!("{} times but {} distances", durations.len(), records.len()).into(),
+            );
+        }
+
+        let runs = durations
+            .iter()
+            .zip(&records)
+            .map(|(duration, record)| {
+                Ok(Run {
+                    duration: duration.parse()?,
+                    record: record.parse()?,
+                })
+            })
+            .collect::<Result<_, ParseIntError>>()?;
+        let joined = Run {
+            duration: durations.concat().parse()?,
+            record: records.concat().parse()?,
+        };
+
+        Ok(Sheet { runs, joined })
+    }
+}
+
+/// How many hold times beat `record`.
+///
+/// Holding for `hold` travels `hold * (duration - hold)`, a parabola.
+/// The winning holds lie strictly between its intersections with `record`,
+/// found with an integer square root and then nudged onto the exact boundary.
+fn ways(duration: u64, record: u64) -> u64 {
+    let wins = |hold: u64| hold as u128 * (duration - hold) as u128 > record as u128;
+
+    let duration_squared = duration as u128 * duration as u128;
+    let Some(discriminant) = duration_squared.checked_sub(4 * record as u128) else {
+        return 0;
+    };
+    // Only within one of the real root, the checks below settle it
+    let mut first = ((duration as u128 - discriminant.isqrt()) / 2) as u64;
+    while first > 0 && wins(first - 1) {
+        first -= 1;
+    }
+    while first <= duration / 2 && !wins(first) {
+        first += 1;
+    }
+    if first > duration / 2 {
+        return 0;
+    }
+
+    // Symmetric around `duration / 2`
+    let last = duration - first;
+    last - first + 1
+}
+
+fn a(runs: &[Run]) -> u64 {
+    runs.iter()
+        .map(|run| ways(run.duration, run.record))
+        .product()
+}
+
+fn b(run: &Run) -> u64 {
+    ways(run.duration, run.record)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Wait For It";
+
+    type Parsed = Sheet;
+    type One = u64;
+    type Two = u64;
+
+    fn parse(input: &str) -> aoc23::Result<Self::Parsed> {
+        Sheet::try_from(input)
+    }
+
+    fn part_one(sheet: &Self::Parsed) -> aoc23::Result<Self::One> {
+        Ok(a(&sheet.runs))
+    }
+
+    fn part_two(sheet: &Self::Parsed) -> aoc23::Result<Self::Two> {
+        Ok(b(&sheet.joined))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn examples() {
+        aoc23::examples::check::<Day>(concat!(env!("CARGO_MANIFEST_DIR"), "/examples"));
+    }
+
+    /// Tries every hold time
+    fn brute_force(duration: u64, record: u64) -> u64 {
+        (0..=duration)
+            .filter(|hold| hold * (duration - hold) > record)
+            .count() as u64
+    }
+
+    #[test]
+    fn parse() {
+        let sheet = Sheet::try_from("Time:      7  15   30\nDistance:  9  40  200\n").unwrap();
+        assert_eq!(
+            sheet.runs,
+            [
+                Run {
+                    duration: 7,
+                    record: 9
+                },
+                Run {
+                    duration: 15,
+                    record: 40
+                },
+                Run {
+                    duration: 30,
+                    record: 200
+                },
+            ]
+        );
+        assert_eq!(
+            sheet.joined,
+            Run {
+                duration: 71530,
+                record: 940200
+            }
+        );
+
+        assert!(Sheet::try_from("Time: 7 15\nDistance: 9\n").is_err());
+        assert!(Sheet::try_from("Time: 7\n").is_err());
+    }
+
+    #[test]
+    fn ties() {
+        // Holding 2 or 4 ms travels exactly 8, that's not a win
+        assert_eq!(ways(6, 8), 1);
+        assert_eq!(ways(6, 9), 0);
+        assert_eq!(ways(30, 200), 9);
+        assert_eq!(ways(0, 0), 0);
+        assert_eq!(ways(1, 0), 0);
+        assert_eq!(ways(2, 0), 1);
+    }
+
+    proptest! {
+        #[test]
+        fn agrees_with_brute_force(duration in 0..3000u64, record in 0..2_300_000u64) {
+            prop_assert_eq!(ways(duration, record), brute_force(duration, record));
+        }
+
+        #[test]
+        fn near_the_best_distance(duration in 0..3000u64, below in 0..3u64) {
+            let best = (duration / 2) * (duration - duration / 2);
+            let record = best.saturating_sub(below);
+            prop_assert_eq!(ways(duration, record), brute_force(duration, record));
+        }
+    }
+}
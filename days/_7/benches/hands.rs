@@ -0,0 +1,59 @@
+//! Ranks a million generated hands under both rulesets,
+//! and under the standard rules with the original solution in [`_7::baseline`] too.
+//!
+//! `cargo bench -p _7 --features baseline`
+
+#[path = "../../../benches/support/mod.rs"]
+mod support;
+
+use std::time::Duration;
+
+use aoc23::{bench::measure, Solution};
+use support::{report, Lcg};
+use _7::{baseline, Day, Jokers, Standard};
+
+const HANDS: usize = 1_000_000;
+const LABELS: &[u8; 13] = b"23456789TJQKA";
+
+fn generate() -> String {
+    let mut lcg = Lcg::new(0x2023_0007);
+    let mut input = String::with_capacity(HANDS * 10);
+    for _ in 0..HANDS {
+        // Equal hands tie, they get the same bid so that the winnings don't depend on their order
+        let mut hand = 0;
+        for _ in 0..5 {
+            let label = lcg.below(LABELS.len() as u64);
+            input.push(LABELS[label as usize] as char);
+            hand = hand * LABELS.len() as u64 + label;
+        }
+        input.push_str(&format!(" {}\n", hand % 1000 + 1));
+    }
+    input
+}
+
+fn main() {
+    let input = generate();
+    let deals = Day::parse(&input).unwrap();
+    assert_eq!(_7::a(&deals, &Standard).unwrap(), baseline::a(&input));
+    let budget = Duration::from_secs(20);
+
+    // The baseline parses the input as it goes
+    report([
+        (
+            "part one, baseline",
+            measure(10, budget, || baseline::a(&input)),
+        ),
+        (
+            "part one",
+            measure(10, budget, || _7::a(&Day::parse(&input)?, &Standard)),
+        ),
+        (
+            "part one, parsed",
+            measure(10, budget, || _7::a(&deals, &Standard)),
+        ),
+        (
+            "part two, parsed",
+            measure(10, budget, || _7::a(&deals, &Jokers)),
+        ),
+    ]);
+}
@@ -1,3 +1,9 @@
+//! Day 7 as it was solved first, before the rulesets, kept to benchmark against.
+//! Classifies hands with a thread local `HashSet` and compares them card by card,
+//! and only knows the standard rules.
+//!
+//! Only built with the `baseline` feature.
+
 use std::{cell::RefCell, cmp::Ordering, collections::HashSet};
 
 use nom::{
@@ -9,8 +15,6 @@ use nom::{
 };
 use rayon::prelude::*;
 
-const INPUT: &str = include_str!("input.txt");
-
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
 enum Label {
     Two,
@@ -51,11 +55,6 @@ impl TryFrom<char> for Label {
     }
 }
 
-#[test]
-fn order_label() {
-    assert!(Label::A > Label::Two)
-}
-
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 #[allow(clippy::enum_variant_names)]
 enum Kind {
@@ -178,7 +177,8 @@ impl From<&str> for Hand {
     }
 }
 
-fn a(input: &str) -> usize {
+/// Part one straight from the input, like it was solved first
+pub fn a(input: &str) -> usize {
     let mut hands: Vec<_> = input.par_lines().map(Hand::from).collect();
 
     hands.sort_unstable();
@@ -189,177 +189,3 @@ fn a(input: &str) -> usize {
         .map(|(rank, hand)| (rank + 1) * hand.bid as usize)
         .sum()
 }
-
-fn b() {
-    let sum = 0;
-
-    println!("{sum}");
-}
-
-fn main() {
-    println!("{}", a(INPUT));
-    b();
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    mod order {
-        use crate::Hand;
-
-        #[test]
-        fn order1() {
-            let a = Hand::from("T66KJ 1");
-            let b = Hand::from("AAKAA 0");
-
-            assert!(a < b)
-        }
-
-        #[test]
-        fn order2() {
-            let a = Hand::from("22224 1");
-            let b = Hand::from("22223 1");
-
-            assert!(a > b)
-        }
-    }
-
-    mod integration {
-        use crate::a;
-
-        #[test]
-        fn integration1() {
-            let input = r#"32T3K 765
-T55J5 684
-KK677 28
-KTJJT 220
-QQQJA 483"#;
-            assert_eq!(a(input), 6440);
-        }
-    }
-
-    mod hand {
-        use crate::Hand;
-        use crate::Kind;
-        use crate::Label::*;
-
-        #[test]
-        fn hand1() {
-            let input = "32T3K 765";
-            let hand = Hand::from(input);
-            assert_eq!(
-                hand,
-                Hand {
-                    kind: Kind::OnePair,
-                    cards: [Three, Two, T, Three, K],
-                    bid: 765
-                }
-            )
-        }
-
-        #[test]
-        fn hand2() {
-            let input = "T55J5 684";
-            let hand = Hand::from(input);
-            assert_eq!(
-                hand,
-                Hand {
-                    kind: Kind::ThreeOfAKind,
-                    cards: [T, Five, Five, J, Five],
-                    bid: 684
-                }
-            )
-        }
-
-        #[test]
-        fn hand3() {
-            let input = "KK677 28";
-            let hand = Hand::from(input);
-            assert_eq!(
-                hand,
-                Hand {
-                    kind: Kind::TwoPair,
-                    cards: [K, K, Six, Seven, Seven],
-                    bid: 28
-                }
-            )
-        }
-
-        #[test]
-        fn hand4() {
-            let input = "KTJJT 220";
-            let hand = Hand::from(input);
-            assert_eq!(
-                hand,
-                Hand {
-                    kind: Kind::TwoPair,
-                    cards: [K, T, J, J, T],
-                    bid: 220
-                }
-            )
-        }
-
-        #[test]
-        fn hand5() {
-            let input = "QQQJA 483";
-            let hand = Hand::from(input);
-            assert_eq!(
-                hand,
-                Hand {
-                    kind: Kind::ThreeOfAKind,
-                    cards: [Q, Q, Q, J, A],
-                    bid: 483
-                }
-            )
-        }
-    }
-
-    mod kind {
-        use super::*;
-        use Label::*;
-
-        #[test]
-        fn one_pair() {
-            let hand = [Three, Two, T, Three, K];
-            let kind = Kind::from(&hand);
-            assert_eq!(kind, Kind::OnePair)
-        }
-        #[test]
-        fn two_pair1() {
-            let hand = [K, K, Six, Six, Seven];
-            let kind = Kind::from(&hand);
-            assert_eq!(kind, Kind::TwoPair)
-        }
-        #[test]
-        fn one_pair2() {
-            let hand = [K, T, J, J, T];
-            let kind = Kind::from(&hand);
-            assert_eq!(kind, Kind::TwoPair)
-        }
-        #[test]
-        fn three_of_a_kind1() {
-            let hand = [T, Five, Five, J, Five];
-            let kind = Kind::from(&hand);
-            assert_eq!(kind, Kind::ThreeOfAKind)
-        }
-        #[test]
-        fn three_of_a_kind2() {
-            let hand = [Q, Q, Q, J, A];
-            let kind = Kind::from(&hand);
-            assert_eq!(kind, Kind::ThreeOfAKind)
-        }
-        #[test]
-        fn full_house() {
-            let hand = [A, Q, Q, A, A];
-            let kind = Kind::from(&hand);
-            assert_eq!(kind, Kind::FullHouse)
-        }
-        #[test]
-        fn four_of_a_kind() {
-            let hand = [A, Q, A, A, A];
-            let kind = Kind::from(&hand);
-            assert_eq!(kind, Kind::FourOfAKind)
-        }
-    }
-}
@@ -0,0 +1,554 @@
+use std::{cmp::Ordering, str::FromStr};
+
+use aoc23::Solution;
+use rayon::prelude::*;
+
+#[cfg(feature = "baseline")]
+pub mod baseline;
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub enum Label {
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    T,
+    J,
+    Q,
+    K,
+    A,
+}
+
+impl TryFrom<char> for Label {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Ok(match value {
+            'A' => Self::A,
+            'K' => Self::K,
+            'Q' => Self::Q,
+            'J' => Self::J,
+            'T' => Self::T,
+            '9' => Self::Nine,
+            '8' => Self::Eight,
+            '7' => Self::Seven,
+            '6' => Self::Six,
+            '5' => Self::Five,
+            '4' => Self::Four,
+            '3' => Self::Three,
+            '2' => Self::Two,
+            _ => return Err(()),
+        })
+    }
+}
+
+/// Most cards in a hand, more wouldn't fit into [`Hand`]'s key
+pub const MAX_CARDS: usize = 15;
+
+/// A variant of Camel Cards, played with hands of `N` cards
+///
+/// `N` is at most [`MAX_CARDS`].
+pub trait Ruleset<const N: usize = 5> {
+    /// Every label, weakest first. Breaks ties between hands of the same kind.
+    fn order(&self) -> &[Label];
+
+    /// Labels that stand in for whatever makes the hand strongest
+    fn wildcards(&self) -> &[Label] {
+        &[]
+    }
+
+    /// Wildcards join the most common other label
+    fn kind(&self, cards: &[Label]) -> Kind {
+        let mut histogram = histogram(cards);
+        let wildcards: usize = self
+            .wildcards()
+            .iter()
+            .map(|&label| std::mem::take(&mut histogram[label as usize]))
+            .sum();
+
+        let (mut most, second) = top_two(&histogram);
+        most += wildcards;
+        Kind::from_counts(&[most, second])
+    }
+
+    /// Position in [`Ruleset::order`]
+    ///
+    /// # Panics
+    /// If the label isn't part of the order
+    fn strength(&self, label: Label) -> u8 {
+        self.order()
+            .iter()
+            .position(|&other| other == label)
+            .expect("every label to be ordered") as u8
+    }
+}
+
+pub struct Standard;
+
+impl Ruleset for Standard {
+    fn order(&self) -> &[Label] {
+        use Label::*;
+        &[
+            Two, Three, Four, Five, Six, Seven, Eight, Nine, T, J, Q, K, A,
+        ]
+    }
+}
+
+/// `J`s are jokers, the weakest label on their own
+pub struct Jokers;
+
+impl Ruleset for Jokers {
+    fn order(&self) -> &[Label] {
+        use Label::*;
+        &[
+            J, Two, Three, Four, Five, Six, Seven, Eight, Nine, T, Q, K, A,
+        ]
+    }
+
+    fn wildcards(&self) -> &[Label] {
+        &[Label::J]
+    }
+}
+
+#[test]
+fn order_label() {
+    assert!(Label::A > Label::Two)
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+#[allow(clippy::enum_variant_names)]
+pub enum Kind {
+    /// All distinct
+    HighCard,
+    /// One pair
+    OnePair,
+    /// Two pairs of cards with the same label + one card with a different label
+    TwoPair,
+    /// Three labels are the same
+    ThreeOfAKind,
+    /// 2 distinct - There are only two different labels in the Hand
+    FullHouse,
+    /// 2 distinct - All but one labels are the same
+    FourOfAKind,
+    /// All Labels are the same
+    FiveOfAKind,
+}
+
+/// How often each label appears, indexed by the label
+fn histogram(cards: &[Label]) -> [usize; 13] {
+    let mut histogram = [0; 13];
+    for &card in cards {
+        histogram[card as usize] += 1;
+    }
+    histogram
+}
+
+/// The two highest counts
+fn top_two(histogram: &[usize; 13]) -> (usize, usize) {
+    histogram.iter().fold((0, 0), |(first, second), &count| {
+        if count > first {
+            (count, first)
+        } else {
+            (first, second.max(count))
+        }
+    })
+}
+
+impl Kind {
+    /// From how often the most common labels appear, most common first
+    fn from_counts(counts: &[usize]) -> Self {
+        match counts {
+            [n, ..] if *n >= 5 => Kind::FiveOfAKind,
+            [4, ..] => Kind::FourOfAKind,
+            [3, 2, ..] => Kind::FullHouse,
+            [3, ..] => Kind::ThreeOfAKind,
+            [2, 2, ..] => Kind::TwoPair,
+            [2, ..] => Kind::OnePair,
+            _ => Kind::HighCard,
+        }
+    }
+}
+
+impl From<&[Label]> for Kind {
+    fn from(cards: &[Label]) -> Self {
+        Standard.kind(cards)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct Hand<const N: usize = 5> {
+    kind: Kind,
+    cards: [Label; N],
+    /// The kind followed by [`Ruleset::strength`] of each card, four bits each.
+    /// Comparing it is the same as comparing the kind and then card by card.
+    key: u64,
+    bid: u16,
+}
+
+impl<const N: usize> PartialOrd for Hand<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Ord for Hand<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+impl<const N: usize> Hand<N> {
+    fn new(cards: [Label; N], bid: u16, rules: &impl Ruleset<N>) -> Self {
+        const { assert!(N <= MAX_CARDS, "the cards have to fit into the key") };
+        let kind = rules.kind(&cards);
+        let key = cards.iter().fold(kind as u64, |key, &card| {
+            key << 4 | rules.strength(card) as u64
+        });
+
+        Hand {
+            kind,
+            cards,
+            key,
+            bid,
+        }
+    }
+}
+
+/// A line of the input, before any rules are applied
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Deal {
+    /// The first `len` are dealt, the rest is padding
+    cards: [Label; MAX_CARDS],
+    len: u8,
+    bid: u16,
+}
+
+impl Deal {
+    pub fn cards(&self) -> &[Label] {
+        &self.cards[..self.len as usize]
+    }
+}
+
+impl FromStr for Deal {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (cards, bid) = s
+            .split_once(' ')
+            .ok_or_else(|| format!("expected `<cards> <bid>`, got `{s}`"))?;
+        let len = cards.chars().count();
+        if !(1..=MAX_CARDS).contains(&len) {
+            return Err(format!("a hand has 1 to {MAX_CARDS} cards, not {len}"));
+        }
+        let mut labels = [Label::Two; MAX_CARDS];
+        for (label, card) in labels.iter_mut().zip(cards.chars()) {
+            *label = Label::try_from(card).map_err(|()| format!("`{card}` isn't a card"))?;
+        }
+        let bid = bid
+            .parse()
+            .map_err(|error| format!("`{bid}` isn't a bid: {error}"))?;
+
+        Ok(Deal {
+            cards: labels,
+            len: len as u8,
+            bid,
+        })
+    }
+}
+
+impl From<&str> for Hand {
+    /// # Panics
+    /// If `value` isn't a valid deal
+    fn from(value: &str) -> Self {
+        let deal: Deal = value.parse().unwrap();
+        Hand::new(deal.cards().try_into().unwrap(), deal.bid, &Standard)
+    }
+}
+
+/// Total winnings of `deals`, ranked under `rules`
+pub fn a<const N: usize>(deals: &[Deal], rules: &(impl Ruleset<N> + Sync)) -> aoc23::Result<usize> {
+    let mut hands = deals
+        .par_iter()
+        .map(|deal| {
+            let cards = deal.cards().try_into().map_err(|_| {
+                format!(
+                    "these rules play with {N} cards, not {}",
+                    deal.cards().len()
+                )
+            })?;
+            Ok(Hand::new(cards, deal.bid, rules))
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    hands.sort_unstable();
+
+    Ok(hands
+        .par_iter()
+        .enumerate()
+        .map(|(rank, hand)| (rank + 1) * hand.bid as usize)
+        .sum())
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Camel Cards";
+
+    type Parsed = Vec<Deal>;
+    type One = usize;
+    type Two = usize;
+
+    fn parse(input: &str) -> aoc23::Result<Self::Parsed> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| {
+                line.parse()
+                    .map_err(|error| format!("line {}: {error}", index + 1).into())
+            })
+            .collect()
+    }
+
+    fn part_one(deals: &Self::Parsed) -> aoc23::Result<Self::One> {
+        a(deals, &Standard)
+    }
+
+    fn part_two(deals: &Self::Parsed) -> aoc23::Result<Self::Two> {
+        a(deals, &Jokers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        aoc23::examples::check::<Day>(concat!(env!("CARGO_MANIFEST_DIR"), "/examples"));
+    }
+
+    mod order {
+        use crate::Hand;
+
+        #[test]
+        fn order1() {
+            let a = Hand::from("T66KJ 1");
+            let b = Hand::from("AAKAA 0");
+
+            assert!(a < b)
+        }
+
+        #[test]
+        fn order2() {
+            let a = Hand::from("22224 1");
+            let b = Hand::from("22223 1");
+
+            assert!(a > b)
+        }
+    }
+
+    mod integration {
+        use crate::{a, Day, Jokers, Standard};
+        use aoc23::Solution;
+
+        const EXAMPLE: &str = include_str!("../examples/example.txt");
+
+        #[test]
+        fn integration1() {
+            assert_eq!(a(&Day::parse(EXAMPLE).unwrap(), &Standard).unwrap(), 6440);
+        }
+
+        #[test]
+        fn jokers() {
+            assert_eq!(a(&Day::parse(EXAMPLE).unwrap(), &Jokers).unwrap(), 5905);
+        }
+
+        /// Aces low
+        struct AcesLow;
+
+        impl crate::Ruleset for AcesLow {
+            fn order(&self) -> &[crate::Label] {
+                use crate::Label::*;
+                &[
+                    A, Two, Three, Four, Five, Six, Seven, Eight, Nine, T, J, Q, K,
+                ]
+            }
+        }
+
+        #[test]
+        fn other_rules() {
+            let deals = Day::parse("AA234 1\nKK234 2").unwrap();
+            assert_eq!(a(&deals, &AcesLow).unwrap(), 1 + 2 * 2);
+            assert_eq!(a(&deals, &Standard).unwrap(), 2 + 2);
+            assert!(Day::parse("KKQ23 3\nA2X45 1").is_err());
+        }
+
+        /// Three cards, aces low
+        struct Short;
+
+        impl crate::Ruleset<3> for Short {
+            fn order(&self) -> &[crate::Label] {
+                use crate::Label::*;
+                &[
+                    A, Two, Three, Four, Five, Six, Seven, Eight, Nine, T, J, Q, K,
+                ]
+            }
+        }
+
+        #[test]
+        fn hand_size() {
+            let deals = Day::parse("KKQ 3\nA23 1\n22A 2").unwrap();
+            assert_eq!(a(&deals, &Short).unwrap(), 3 * 3 + 1 + 2 * 2);
+            assert_eq!(
+                a(&deals, &Standard).unwrap_err().to_string(),
+                "these rules play with 5 cards, not 3"
+            );
+            assert!(Day::parse("KKQ 3\nA2X 1").is_err());
+            assert!(Day::parse(&format!("{} 1", "A".repeat(16))).is_err());
+        }
+    }
+
+    mod hand {
+        use crate::Hand;
+        use crate::Kind;
+        use crate::Label::*;
+
+        #[test]
+        fn hand1() {
+            let input = "32T3K 765";
+            let hand = Hand::from(input);
+            assert_eq!(
+                (hand.kind, hand.cards, hand.bid),
+                (Kind::OnePair, [Three, Two, T, Three, K], 765)
+            )
+        }
+
+        #[test]
+        fn hand2() {
+            let input = "T55J5 684";
+            let hand = Hand::from(input);
+            assert_eq!(
+                (hand.kind, hand.cards, hand.bid),
+                (Kind::ThreeOfAKind, [T, Five, Five, J, Five], 684)
+            )
+        }
+
+        #[test]
+        fn hand3() {
+            let input = "KK677 28";
+            let hand = Hand::from(input);
+            assert_eq!(
+                (hand.kind, hand.cards, hand.bid),
+                (Kind::TwoPair, [K, K, Six, Seven, Seven], 28)
+            )
+        }
+
+        #[test]
+        fn hand4() {
+            let input = "KTJJT 220";
+            let hand = Hand::from(input);
+            assert_eq!(
+                (hand.kind, hand.cards, hand.bid),
+                (Kind::TwoPair, [K, T, J, J, T], 220)
+            )
+        }
+
+        #[test]
+        fn hand5() {
+            let input = "QQQJA 483";
+            let hand = Hand::from(input);
+            assert_eq!(
+                (hand.kind, hand.cards, hand.bid),
+                (Kind::ThreeOfAKind, [Q, Q, Q, J, A], 483)
+            )
+        }
+    }
+
+    mod kind {
+        use super::*;
+        use Label::*;
+
+        #[test]
+        fn one_pair() {
+            let hand = [Three, Two, T, Three, K];
+            let kind = Kind::from(&hand[..]);
+            assert_eq!(kind, Kind::OnePair)
+        }
+        #[test]
+        fn two_pair1() {
+            let hand = [K, K, Six, Six, Seven];
+            let kind = Kind::from(&hand[..]);
+            assert_eq!(kind, Kind::TwoPair)
+        }
+        #[test]
+        fn one_pair2() {
+            let hand = [K, T, J, J, T];
+            let kind = Kind::from(&hand[..]);
+            assert_eq!(kind, Kind::TwoPair)
+        }
+        #[test]
+        fn three_of_a_kind1() {
+            let hand = [T, Five, Five, J, Five];
+            let kind = Kind::from(&hand[..]);
+            assert_eq!(kind, Kind::ThreeOfAKind)
+        }
+        #[test]
+        fn three_of_a_kind2() {
+            let hand = [Q, Q, Q, J, A];
+            let kind = Kind::from(&hand[..]);
+            assert_eq!(kind, Kind::ThreeOfAKind)
+        }
+        #[test]
+        fn full_house() {
+            let hand = [A, Q, Q, A, A];
+            let kind = Kind::from(&hand[..]);
+            assert_eq!(kind, Kind::FullHouse)
+        }
+        #[test]
+        fn four_of_a_kind() {
+            let hand = [A, Q, A, A, A];
+            let kind = Kind::from(&hand[..]);
+            assert_eq!(kind, Kind::FourOfAKind)
+        }
+        #[test]
+        fn histogram() {
+            let histogram = crate::histogram(&[K, T, J, J, T]);
+            assert_eq!(histogram[K as usize], 1);
+            assert_eq!(histogram[T as usize], 2);
+            assert_eq!(crate::top_two(&histogram), (2, 2));
+            assert_eq!(crate::top_two(&crate::histogram(&[A; 5])), (5, 0));
+        }
+        #[test]
+        fn jokers() {
+            assert_eq!(Jokers.kind(&[K, T, J, J, T]), Kind::FourOfAKind);
+            assert_eq!(Jokers.kind(&[Three, Two, T, J, K]), Kind::OnePair);
+            assert_eq!(Jokers.kind(&[Two, Two, Three, Three, J]), Kind::FullHouse);
+            assert_eq!(Jokers.kind(&[J; 5]), Kind::FiveOfAKind);
+            assert_eq!(Standard.kind(&[J; 5]), Kind::FiveOfAKind);
+        }
+        #[test]
+        fn joker_is_weakest() {
+            let hand = |cards: &str| {
+                crate::Hand::new(
+                    cards
+                        .chars()
+                        .map(|card| Label::try_from(card).unwrap())
+                        .collect::<Vec<_>>()
+                        .try_into()
+                        .unwrap(),
+                    1,
+                    &Jokers,
+                )
+            };
+            let a = hand("JKKK2");
+            let b = hand("QQQQ2");
+            assert_eq!(a.kind, b.kind);
+            assert!(a < b)
+        }
+    }
+}
@@ -0,0 +1,126 @@
+//! Walks a generated network that's much larger than the puzzle input, in the same shape:
+//! every `..A` start runs around its own loop, one `..Z` per loop. `AAA` is one of them.
+//! The baseline is the first solution in [`_8::baseline`], which looks every node up by name,
+//! the current walks use the interned tables.
+//!
+//! `cargo bench -p _8 --features baseline`
+
+#[path = "../../../benches/support/mod.rs"]
+mod support;
+
+use std::{fmt::Write, time::Duration};
+
+use aoc23::{bench::measure, Solution};
+use support::{report, Lcg};
+use _8::{baseline, Day};
+
+const INSTRUCTIONS: usize = 263;
+/// Every start's loop is this many rounds of instructions long, `AAA` goes first
+const ROUNDS: [usize; 6] = [31, 11, 13, 17, 19, 23];
+
+fn generate() -> String {
+    let mut lcg = Lcg::new(0x2023_0008);
+    let mut input: String = (0..INSTRUCTIONS)
+        .map(|_| if lcg.below(2) == 0 { 'L' } else { 'R' })
+        .collect();
+    input.push_str("\n\n");
+
+    // Never ends in `A` or `Z`, those are spelled out
+    const FIRST: &[u8; 36] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+    const LAST: &[u8; 34] = b"BCDEFGHIJKLMNOPQRSTUVWXY0123456789";
+    let mut names = (0..).map(|i: usize| {
+        let mut name = String::with_capacity(3);
+        name.push(FIRST[i / LAST.len() / FIRST.len()] as char);
+        name.push(FIRST[i / LAST.len() % FIRST.len()] as char);
+        name.push(LAST[i % LAST.len()] as char);
+        name
+    });
+    for (ghost, rounds) in ROUNDS.into_iter().enumerate() {
+        let (start, end) = match ghost {
+            0 => ("AAA".to_string(), "ZZZ".to_string()),
+            _ => (format!("{ghost}{ghost}A"), format!("{ghost}{ghost}Z")),
+        };
+        // Back to the node right after the start, so `..Z` comes around every `rounds` rounds
+        let after_start = names.next().unwrap();
+        writeln!(input, "{start} = ({after_start}, {after_start})").unwrap();
+        chain(
+            &mut input,
+            &mut names,
+            &after_start,
+            rounds * INSTRUCTIONS - 1,
+            &end,
+        );
+        writeln!(input, "{end} = ({after_start}, {after_start})").unwrap();
+    }
+    input
+}
+
+/// `from` and `length - 1` new nodes in a row, with both ways leading on, then `to`
+fn chain(
+    input: &mut String,
+    names: &mut impl Iterator<Item = String>,
+    from: &str,
+    length: usize,
+    to: &str,
+) {
+    let mut current = from.to_string();
+    for _ in 1..length {
+        let next = names.next().unwrap();
+        writeln!(input, "{current} = ({next}, {next})").unwrap();
+        current = next;
+    }
+    writeln!(input, "{current} = ({to}, {to})").unwrap();
+}
+
+fn main() {
+    let input = generate();
+    let parsed = Day::parse(&input).unwrap();
+    let (one, two) = (
+        ROUNDS[0] * INSTRUCTIONS,
+        INSTRUCTIONS * ROUNDS.iter().product::<usize>(),
+    );
+    assert_eq!(Day::part_one(&parsed).unwrap(), one);
+    assert_eq!(Day::part_two(&parsed).unwrap(), two);
+    assert_eq!(baseline::a(&input), one);
+    assert_eq!(baseline::b(&input), two);
+    let budget = Duration::from_secs(20);
+
+    // The baseline parses the input again for every part
+    report([
+        (
+            "parse, baseline",
+            measure(10, budget, || {
+                baseline::Map::try_from(input.as_str()).unwrap()
+            }),
+        ),
+        ("parse", measure(10, budget, || Day::parse(&input).unwrap())),
+        (
+            "part one, baseline",
+            measure(10, budget, || baseline::a(&input)),
+        ),
+        (
+            "part one",
+            measure(10, budget, || {
+                Day::part_one(&Day::parse(&input).unwrap()).unwrap()
+            }),
+        ),
+        (
+            "part one, parsed",
+            measure(10, budget, || Day::part_one(&parsed).unwrap()),
+        ),
+        (
+            "part two, baseline",
+            measure(10, budget, || baseline::b(&input)),
+        ),
+        (
+            "part two",
+            measure(10, budget, || {
+                Day::part_two(&Day::parse(&input).unwrap()).unwrap()
+            }),
+        ),
+        (
+            "part two, parsed",
+            measure(10, budget, || Day::part_two(&parsed).unwrap()),
+        ),
+    ]);
+}
@@ -1,3 +1,10 @@
+//! Day 8 as it was solved first, kept to benchmark against. Every step looks its node up in a
+//! `HashMap` by name, and part two takes the lcm of the steps each `..A` start needs to its first
+//! `..Z`, which only works for networks shaped like the puzzle's. Errors are never reported and
+//! a missing node panics.
+//!
+//! Only built with the `baseline` feature.
+
 use std::{
     collections::HashMap,
     error::Error,
@@ -8,8 +15,6 @@ use nom::Finish;
 use parse::row;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
-const INPUT: &str = include_str!("input.txt");
-
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 enum Instruction {
     L,
@@ -17,7 +22,7 @@ enum Instruction {
 }
 
 #[derive(Debug)]
-enum ErrorKind {
+pub enum ErrorKind {
     NotAnInstruction(char),
     NoInstructionsFound,
     CouldntParseInstructions,
@@ -26,7 +31,7 @@ enum ErrorKind {
 }
 
 #[derive(Debug)]
-struct ParseError {
+pub struct ParseError {
     kind: ErrorKind,
 }
 
@@ -113,8 +118,9 @@ mod parse {
     }
 }
 
+/// Every step looks its node up by name
 #[derive(Debug)]
-struct Map {
+pub struct Map {
     instructions: Vec<Instruction>,
     nodes: HashMap<Node, Crossroad>,
 }
@@ -202,45 +208,16 @@ impl TryFrom<&str> for Map {
     }
 }
 
-fn a(input: &str) -> usize {
+/// Part one, parsing included
+pub fn a(input: &str) -> usize {
     let map: Map = input.try_into().expect("the input to be parseable");
 
     map.steps(&Node('A', 'A', 'A'), &Node('Z', 'Z', 'Z'))
 }
 
-fn b(input: &str) -> usize {
+/// Part two, parsing included
+pub fn b(input: &str) -> usize {
     let map: Map = input.try_into().expect("the input to be parseable");
 
     map.parallel_steps()
 }
-
-fn main() {
-    let steps = a(INPUT);
-    println!("{steps}");
-
-    let steps = b(INPUT);
-    println!("{steps}")
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn parallel1() {
-        let input = r#"LR
-
-11A = (11B, XXX)
-11B = (XXX, 11Z)
-11Z = (11B, XXX)
-22A = (22B, XXX)
-22B = (22C, 22C)
-22C = (22Z, 22Z)
-22Z = (22B, 22B)
-XXX = (XXX, XXX)"#;
-
-        let steps = b(input);
-
-        assert_eq!(steps, 6);
-    }
-}
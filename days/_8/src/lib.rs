@@ -0,0 +1,917 @@
+use std::{
+    collections::HashMap,
+    error::Error,
+    fmt::{Display, Write},
+};
+
+use aoc23::Solution;
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+#[cfg(feature = "baseline")]
+pub mod baseline;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Instruction {
+    L = 0,
+    R = 1,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum ErrorKind {
+    NotAnInstruction(char),
+    NoInstructionsFound,
+    NoEmptyLineAfterInstructions,
+    /// What a node line is missing at the error's column
+    Expected(&'static str),
+    UndefinedNode(Node),
+    /// With the line of the first definition
+    DuplicateNode(Node, usize),
+    TooManyNodes,
+    /// Part one walks from `AAA` to `ZZZ`
+    MissingNode(Node),
+    Unreachable(Node, Node),
+    NoStarts,
+    NeverTogether,
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ErrorKind::NotAnInstruction(c) => write!(f, "`{c}` is not an instruction"),
+            ErrorKind::NoInstructionsFound => write!(f, "expected a line of `L`s and `R`s"),
+            ErrorKind::NoEmptyLineAfterInstructions => {
+                write!(f, "expected an empty line after the instructions")
+            }
+            ErrorKind::Expected(what) => write!(f, "expected {what}"),
+            ErrorKind::UndefinedNode(node) => write!(f, "`{node}` is used but never defined"),
+            ErrorKind::DuplicateNode(node, first) => {
+                write!(f, "`{node}` is already defined on line {}", first + 1)
+            }
+            ErrorKind::TooManyNodes => write!(f, "more than {} nodes", Id::MAX as usize + 1),
+            ErrorKind::MissingNode(node) => write!(f, "there's no node `{node}`"),
+            ErrorKind::Unreachable(from, to) => write!(f, "`{to}` can't be reached from `{from}`"),
+            ErrorKind::NoStarts => write!(f, "no node ends in `A`"),
+            ErrorKind::NeverTogether => {
+                write!(
+                    f,
+                    "the nodes ending in `A` never all reach one ending in `Z` together"
+                )
+            }
+        }
+    }
+}
+
+/// Where in the input something went wrong
+#[derive(Debug, PartialEq, Eq)]
+struct Location {
+    /// Both counted from 1
+    line: usize,
+    column: usize,
+    /// The line, or the part of it around `column` if it's long
+    snippet: String,
+}
+
+impl Location {
+    /// Lines longer than this are cut down to the part around the column
+    const SNIPPET: usize = 40;
+
+    /// `line` counts from 0 and `offset` is in bytes, as they come from the parser
+    fn new(line: usize, text: &str, offset: usize) -> Self {
+        let column = text[..offset].chars().count();
+        let length = text.chars().count();
+        let snippet = if length <= Self::SNIPPET {
+            text.to_string()
+        } else {
+            let start = column.saturating_sub(Self::SNIPPET / 2);
+            let end = (start + Self::SNIPPET).min(length);
+            let mut snippet = String::new();
+            if start > 0 {
+                snippet.push('…');
+            }
+            snippet.extend(text.chars().skip(start).take(end - start));
+            if end < length {
+                snippet.push('…');
+            }
+            snippet
+        };
+
+        Location {
+            line: line + 1,
+            column: column + 1,
+            snippet,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseError {
+    kind: ErrorKind,
+    /// `None` for problems with the map as a whole
+    location: Option<Location>,
+}
+
+impl ParseError {
+    fn at(kind: ErrorKind, line: usize, text: &str, offset: usize) -> Self {
+        ParseError {
+            kind,
+            location: Some(Location::new(line, text, offset)),
+        }
+    }
+}
+
+impl From<ErrorKind> for ParseError {
+    fn from(kind: ErrorKind) -> Self {
+        ParseError {
+            kind,
+            location: None,
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.location {
+            Some(Location {
+                line,
+                column,
+                snippet,
+            }) => write!(
+                f,
+                "line {line}, column {column} of `{snippet}`: {}",
+                self.kind
+            ),
+            None => self.kind.fmt(f),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl TryFrom<char> for Instruction {
+    type Error = ErrorKind;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'L' | 'l' => Ok(Self::L),
+            'R' | 'r' => Ok(Self::R),
+            _ => Err(ErrorKind::NotAnInstruction(value)),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+struct Node(char, char, char);
+
+impl Display for Node {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_char(self.0)?;
+        f.write_char(self.1)?;
+        f.write_char(self.2)
+    }
+}
+
+/// A node's position in [`Map`]'s tables, handed out by the parser in order of appearance
+type Id = u16;
+
+/// A set of nodes, one bit per [`Id`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Nodes(Vec<u64>);
+
+impl Nodes {
+    fn new(len: usize) -> Self {
+        Nodes(vec![0; len.div_ceil(64)])
+    }
+
+    fn insert(&mut self, id: Id) {
+        self.0[id as usize / 64] |= 1 << (id % 64);
+    }
+
+    fn contains(&self, id: Id) -> bool {
+        self.0[id as usize / 64] & (1 << (id % 64)) != 0
+    }
+
+    fn iter(&self) -> impl Iterator<Item = Id> + '_ {
+        self.0.iter().enumerate().flat_map(|(word, &bits)| {
+            (0..64)
+                .filter(move |bit| bits & (1 << bit) != 0)
+                .map(move |bit| (word * 64 + bit) as Id)
+        })
+    }
+}
+
+/// An `AAA = (BBB, CCC)` line
+#[derive(Debug)]
+struct Row<'a> {
+    /// Counted from 0
+    line: usize,
+    text: &'a str,
+    /// The node, where it goes left and where it goes right, each with its offset in `text`
+    names: [(Node, usize); 3],
+}
+
+impl Row<'_> {
+    fn error(&self, kind: ErrorKind, offset: usize) -> ParseError {
+        ParseError::at(kind, self.line, self.text, offset)
+    }
+}
+
+mod parse {
+    use nom::{
+        bytes::complete::tag,
+        character::complete::{char, satisfy},
+        combinator::map,
+        sequence::tuple,
+        Finish, IResult, Parser,
+    };
+
+    use super::*;
+
+    pub fn node(input: &str) -> IResult<&str, Node> {
+        let name = || satisfy(|c| c.is_ascii_alphanumeric());
+        map(tuple((name(), name(), name())), |chars| {
+            Node(chars.0, chars.1, chars.2)
+        })(input)
+    }
+
+    /// `(the rest, what it parsed)` or what was expected at which offset of `text`
+    fn expect<'a, T>(
+        text: &'a str,
+        input: &'a str,
+        expected: &'static str,
+        mut parser: impl Parser<&'a str, T, nom::error::Error<&'a str>>,
+    ) -> Result<(&'a str, T), (ErrorKind, usize)> {
+        parser
+            .parse(input)
+            .finish()
+            .map_err(|_| (ErrorKind::Expected(expected), text.len() - input.len()))
+    }
+
+    pub fn row(line: usize, text: &str) -> Result<Row<'_>, ParseError> {
+        let row = || {
+            let name = "a node name like `AAA`";
+            let offset = |input: &str| text.len() - input.len();
+
+            let (input, defined) = expect(text, text, name, node)?;
+            let (input, _) = expect(text, input, "` = `", tag(" = "))?;
+            let (input, _) = expect(text, input, "`(`", char('('))?;
+            let left = offset(input);
+            let (input, left_node) = expect(text, input, name, node)?;
+            let (input, _) = expect(text, input, "`, `", tag(", "))?;
+            let right = offset(input);
+            let (input, right_node) = expect(text, input, name, node)?;
+            let (input, _) = expect(text, input, "`)`", char(')'))?;
+            if !input.is_empty() {
+                return Err((ErrorKind::Expected("the end of the line"), offset(input)));
+            }
+
+            Ok([(defined, 0), (left_node, left), (right_node, right)])
+        };
+
+        row()
+            .map(|names| Row { line, text, names })
+            .map_err(|(kind, offset)| ParseError::at(kind, line, text, offset))
+    }
+
+    pub fn instructions(text: &str) -> Result<Vec<Instruction>, ParseError> {
+        if text.is_empty() {
+            return Err(ParseError::at(ErrorKind::NoInstructionsFound, 0, text, 0));
+        }
+        text.char_indices()
+            .map(|(offset, c)| {
+                Instruction::try_from(c).map_err(|kind| ParseError::at(kind, 0, text, offset))
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+pub struct Map {
+    instructions: Vec<Instruction>,
+    /// The name of every [`Id`]
+    names: Vec<Node>,
+    /// `[left, right]` of every node, indexed by [`Instruction`]
+    next: Vec<[Id; 2]>,
+    /// Nodes ending in `A`
+    starts: Nodes,
+    /// Nodes ending in `Z`
+    ends: Nodes,
+    /// Where every node ends up after following all instructions once
+    jump: Vec<Id>,
+    /// Nodes that pass one ending in `Z` on the way to their [`Map::jump`], not counting themselves
+    passes_end: Nodes,
+}
+
+impl Map {
+    fn new(instructions: Vec<Instruction>, names: Vec<Node>, next: Vec<[Id; 2]>) -> Self {
+        let mut starts = Nodes::new(names.len());
+        let mut ends = Nodes::new(names.len());
+        for (id, name) in names.iter().enumerate() {
+            match name.2 {
+                'A' => starts.insert(id as Id),
+                'Z' => ends.insert(id as Id),
+                _ => {}
+            }
+        }
+
+        // Every node takes one step per instruction, so each round only looks at one column of `next`
+        let mut jump: Vec<Id> = (0..names.len()).map(|id| id as Id).collect();
+        let mut passes_end = Nodes::new(names.len());
+        for &instruction in &instructions {
+            for (id, at) in jump.iter_mut().enumerate() {
+                *at = next[*at as usize][instruction as usize];
+                if ends.contains(*at) {
+                    passes_end.insert(id as Id);
+                }
+            }
+        }
+
+        Map {
+            instructions,
+            names,
+            next,
+            starts,
+            ends,
+            jump,
+            passes_end,
+        }
+    }
+
+    fn id(&self, node: Node) -> Option<Id> {
+        self.names
+            .iter()
+            .position(|&name| name == node)
+            .map(|id| id as Id)
+    }
+
+    fn turn(&self, from: Id, instruction: Instruction) -> Id {
+        self.next[from as usize][instruction as usize]
+    }
+
+    /// `None` if `to` can't be reached from `from`
+    fn steps(&self, from: Id, to: Id) -> Option<usize> {
+        if from == to {
+            return Some(0);
+        }
+        // After this many steps the walk has been at every (node, instruction) it'll ever be at
+        let states = self.names.len() * self.instructions.len();
+        let mut current = from;
+        for (steps, &instruction) in self.instructions.iter().cycle().take(states).enumerate() {
+            current = self.turn(current, instruction);
+            if current == to {
+                return Some(steps + 1);
+            }
+        }
+        None
+    }
+
+    /// Part one, from `AAA` to `ZZZ`
+    fn aaa_to_zzz(&self) -> Result<usize, ParseError> {
+        let (aaa, zzz) = (Node('A', 'A', 'A'), Node('Z', 'Z', 'Z'));
+        let id = |node| self.id(node).ok_or(ErrorKind::MissingNode(node));
+        Ok(self
+            .steps(id(aaa)?, id(zzz)?)
+            .ok_or(ErrorKind::Unreachable(aaa, zzz))?)
+    }
+
+    /// Where the walk from `start` ends up on a `..Z` node, see [`Ghost`]
+    ///
+    /// Only the node at the start of every round of instructions is remembered, so the tail
+    /// is a whole number of rounds too and may include a few steps of the repetition.
+    fn ghost(&self, start: Id) -> Ghost {
+        let rounds = self.instructions.len();
+        let mut seen = vec![None; self.names.len()];
+        let mut hits = Vec::new();
+        let mut current = start;
+
+        for round in 0.. {
+            if let Some(first) = seen[current as usize] {
+                let tail = first * rounds;
+                let tail_hits = hits.iter().take_while(|&&hit| hit < tail).count();
+                let cycle_hits = hits.split_off(tail_hits);
+                return Ghost {
+                    start: self.names[start as usize],
+                    tail,
+                    cycle: (round - first) * rounds,
+                    tail_hits: hits,
+                    cycle_hits,
+                };
+            }
+            seen[current as usize] = Some(round);
+
+            if self.passes_end.contains(current) {
+                let mut at = current;
+                for (step, &instruction) in self.instructions.iter().enumerate() {
+                    at = self.turn(at, instruction);
+                    if self.ends.contains(at) {
+                        hits.push(round * rounds + step + 1);
+                    }
+                }
+            }
+            current = self.jump[current as usize];
+        }
+        unreachable!()
+    }
+
+    /// The walk of every `..A` start
+    fn ghosts(&self) -> Result<Vec<Ghost>, ParseError> {
+        let starters: Vec<_> = self.starts.iter().collect();
+        if starters.is_empty() {
+            return Err(ErrorKind::NoStarts.into());
+        }
+        Ok(starters
+            .par_iter()
+            .map(|&start| self.ghost(start))
+            .collect())
+    }
+
+    /// When every `..A` start is on a `..Z` node at the same time
+    fn parallel_steps(&self) -> Result<usize, ParseError> {
+        Ok(together(&self.ghosts()?).ok_or(ErrorKind::NeverTogether)?)
+    }
+
+    /// Why the lcm of the first `..Z` of every start would be the wrong answer for part two,
+    /// one reason per start it doesn't work for
+    pub fn lcm_shortcut_problems(&self) -> Vec<String> {
+        self.ghosts()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|ghost| ghost.lcm_shortcut().err())
+            .collect()
+    }
+}
+
+/// The walk from one start, as seen by the `..Z` nodes.
+///
+/// After `tail` steps the walk is at a (node, instruction) it has been at before,
+/// so from there on everything repeats every `cycle` steps.
+#[derive(Debug, PartialEq, Eq)]
+struct Ghost {
+    start: Node,
+    tail: usize,
+    cycle: usize,
+    /// Steps at which the walk is on a `..Z` node before the repetition starts, those happen once
+    tail_hits: Vec<usize>,
+    /// Steps at which the walk is on a `..Z` node during the first repetition,
+    /// each also happens `cycle`, `2 * cycle`, … steps later
+    cycle_hits: Vec<usize>,
+}
+
+impl Ghost {
+    fn on_z(&self, steps: usize) -> bool {
+        if steps < self.tail {
+            self.tail_hits.contains(&steps)
+        } else {
+            let offset = self.tail + (steps - self.tail) % self.cycle;
+            self.cycle_hits.contains(&offset)
+        }
+    }
+
+    /// The usual shortcut takes the first hit and assumes there's one exactly every that many steps
+    fn lcm_shortcut(&self) -> Result<usize, String> {
+        let start = self.start;
+        let Some(&first) = self.tail_hits.first().or(self.cycle_hits.first()) else {
+            return Err(format!("{start} never reaches a `..Z` node"));
+        };
+        if !self.cycle.is_multiple_of(first) {
+            return Err(format!(
+                "{start} first reaches a `..Z` node after {first} steps, but repeats every {}",
+                self.cycle
+            ));
+        }
+        match (1..=self.tail + self.cycle)
+            .find(|&steps| self.on_z(steps) != steps.is_multiple_of(first))
+        {
+            Some(steps) if self.on_z(steps) => Err(format!(
+                "{start} is on a `..Z` node after {steps} steps, not a multiple of {first}"
+            )),
+            Some(steps) => Err(format!(
+                "{start} isn't on a `..Z` node after {steps} steps, a multiple of {first}"
+            )),
+            None => Ok(first),
+        }
+    }
+}
+
+/// More combinations of hits than this aren't worth solving one by one
+const MAX_COMBINATIONS: usize = 100_000;
+
+/// The first step at which all ghosts are on a `..Z` node
+fn together(ghosts: &[Ghost]) -> Option<usize> {
+    let on_z = |steps: usize| ghosts.iter().all(|ghost| ghost.on_z(steps));
+
+    // Hits that only happen once don't fit into any system of congruences, so check them directly
+    let once = ghosts
+        .iter()
+        .flat_map(|ghost| ghost.tail_hits.iter().copied())
+        .filter(|&steps| on_z(steps))
+        .min();
+
+    let combinations = ghosts.iter().try_fold(1usize, |combinations, ghost| {
+        combinations
+            .checked_mul(ghost.cycle_hits.len())
+            .filter(|&combinations| combinations <= MAX_COMBINATIONS)
+    });
+    let repeating = match combinations {
+        Some(_) => repeating(ghosts),
+        None => simulate(ghosts),
+    };
+
+    match (once, repeating) {
+        (Some(once), Some(repeating)) => Some(once.min(repeating)),
+        (once, repeating) => once.or(repeating),
+    }
+}
+
+/// Solves `steps ≡ hit (mod cycle)` for every combination of one cycle hit per ghost
+fn repeating(ghosts: &[Ghost]) -> Option<usize> {
+    // (remainder, modulus, lowest valid steps)
+    let mut systems = vec![(0i128, 1i128, 0usize)];
+    for ghost in ghosts {
+        systems = systems
+            .iter()
+            .flat_map(|&(remainder, modulus, lowest)| {
+                ghost.cycle_hits.iter().filter_map(move |&hit| {
+                    let (remainder, modulus) =
+                        crt((remainder, modulus), (hit as i128, ghost.cycle as i128))?;
+                    Some((remainder, modulus, lowest.max(hit)))
+                })
+            })
+            .collect();
+    }
+
+    systems
+        .into_iter()
+        .filter_map(|(remainder, modulus, lowest)| {
+            let lowest = lowest.max(1) as i128;
+            let steps = if remainder >= lowest {
+                remainder
+            } else {
+                remainder + (lowest - remainder + modulus - 1) / modulus * modulus
+            };
+            usize::try_from(steps).ok()
+        })
+        .min()
+}
+
+/// Goes through the hits of the ghost that's on `..Z` the least often and asks the others,
+/// until everything has repeated
+fn simulate(ghosts: &[Ghost]) -> Option<usize> {
+    let sparsest = ghosts
+        .iter()
+        .min_by_key(|ghost| (ghost.cycle_hits.len() * 1_000_000) / ghost.cycle.max(1))?;
+    let period = ghosts
+        .iter()
+        .try_fold(1usize, |period, ghost| {
+            period.checked_mul(ghost.cycle / num::integer::gcd(period, ghost.cycle))
+        })
+        .unwrap_or(usize::MAX);
+    let tail = ghosts.iter().map(|ghost| ghost.tail).max().unwrap_or(0);
+    let end = tail.saturating_add(period);
+
+    (0..)
+        .map(|repetition| sparsest.tail + repetition * sparsest.cycle)
+        .take_while(|&start| start < end)
+        .flat_map(|start| {
+            sparsest
+                .cycle_hits
+                .iter()
+                .map(move |&hit| hit - sparsest.tail + start)
+        })
+        .find(|&steps| ghosts.iter().all(|ghost| ghost.on_z(steps)))
+}
+
+/// Combines `x ≡ a (mod m)` and `x ≡ b (mod n)` into one congruence, if there's any solution.
+/// The moduli don't need to be coprime.
+fn crt((a, m): (i128, i128), (b, n): (i128, i128)) -> Option<(i128, i128)> {
+    let (gcd, p, _) = extended_gcd(m, n);
+    if (b - a) % gcd != 0 {
+        return None;
+    }
+    let lcm = m / gcd * n;
+    let k = ((b - a) / gcd * p).rem_euclid(n / gcd);
+    Some(((a + m * k).rem_euclid(lcm), lcm))
+}
+
+/// `(gcd, p, q)` with `p * a + q * b = gcd`
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (gcd, p, q) = extended_gcd(b, a % b);
+        (gcd, q, p - a / b * q)
+    }
+}
+
+impl TryFrom<&str> for Map {
+    type Error = ParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        let mut lines = s.lines().enumerate();
+        let instructions = parse::instructions(lines.next().map_or("", |(_, text)| text))?;
+        match lines.next() {
+            Some((_, "")) => {}
+            Some((line, text)) => {
+                return Err(ParseError::at(
+                    ErrorKind::NoEmptyLineAfterInstructions,
+                    line,
+                    text,
+                    0,
+                ))
+            }
+            None => {
+                return Err(ParseError::at(
+                    ErrorKind::NoEmptyLineAfterInstructions,
+                    1,
+                    "",
+                    0,
+                ))
+            }
+        }
+        let rows = lines
+            .map(|(line, text)| parse::row(line, text))
+            .collect::<Result<Vec<_>, _>>()?;
+        validate(&rows)?;
+
+        let mut ids = HashMap::new();
+        let mut names = Vec::new();
+        let mut intern = |node: Node, row: &Row, offset: usize| -> Result<Id, ParseError> {
+            if let Some(&id) = ids.get(&node) {
+                return Ok(id);
+            }
+            let id = Id::try_from(names.len())
+                .map_err(|_| row.error(ErrorKind::TooManyNodes, offset))?;
+            ids.insert(node, id);
+            names.push(node);
+            Ok(id)
+        };
+
+        let mut next = vec![[0; 2]; rows.len()];
+        for row in &rows {
+            let [(node, _), (left, left_offset), (right, right_offset)] = row.names;
+            let id = intern(node, row, 0)?;
+            next[id as usize] = [
+                intern(left, row, left_offset)?,
+                intern(right, row, right_offset)?,
+            ];
+        }
+
+        Ok(Map::new(instructions, names, next))
+    }
+}
+
+/// Every node is defined once, and only defined nodes are used
+fn validate(rows: &[Row]) -> Result<(), ParseError> {
+    let mut defined = HashMap::new();
+    for row in rows {
+        let node = row.names[0].0;
+        if let Some(&first) = defined.get(&node) {
+            return Err(row.error(ErrorKind::DuplicateNode(node, first), 0));
+        }
+        defined.insert(node, row.line);
+    }
+
+    for row in rows {
+        for &(node, offset) in &row.names[1..] {
+            if !defined.contains_key(&node) {
+                return Err(row.error(ErrorKind::UndefinedNode(node), offset));
+            }
+        }
+    }
+    Ok(())
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Haunted Wasteland";
+
+    type Parsed = Map;
+    type One = usize;
+    type Two = usize;
+
+    fn parse(input: &str) -> aoc23::Result<Self::Parsed> {
+        Ok(Map::try_from(input)?)
+    }
+
+    fn part_one(map: &Self::Parsed) -> aoc23::Result<Self::One> {
+        Ok(map.aaa_to_zzz()?)
+    }
+
+    fn part_two(map: &Self::Parsed) -> aoc23::Result<Self::Two> {
+        Ok(map.parallel_steps()?)
+    }
+
+    fn notes(map: &Self::Parsed) -> Vec<String> {
+        let missing = [Node('A', 'A', 'A'), Node('Z', 'Z', 'Z')]
+            .into_iter()
+            .filter(|&node| map.id(node).is_none())
+            .map(|node| format!("part one can't walk, {}", ErrorKind::MissingNode(node)));
+        let lcm = map.lcm_shortcut_problems().into_iter().map(|problem| {
+            format!("the lcm of the first `..Z` of every start would be wrong: {problem}")
+        });
+        missing.chain(lcm).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ghost(map: &Map, start: &str) -> Ghost {
+        let (_, start) = parse::node(start).unwrap();
+        map.ghost(map.id(start).unwrap())
+    }
+
+    #[test]
+    fn examples() {
+        aoc23::examples::check::<Day>(concat!(env!("CARGO_MANIFEST_DIR"), "/examples"));
+    }
+
+    const PART_TWO: &str = include_str!("../examples/part_two.txt");
+
+    #[test]
+    fn part_two_example_has_no_aaa_or_zzz() {
+        let map = Day::parse(PART_TWO).unwrap();
+        assert_eq!(
+            Day::notes(&map),
+            [
+                "part one can't walk, there's no node `AAA`",
+                "part one can't walk, there's no node `ZZZ`"
+            ]
+        );
+        assert_eq!(
+            Day::part_one(&map).unwrap_err().to_string(),
+            "there's no node `AAA`"
+        );
+    }
+
+    #[test]
+    fn parallel1() {
+        let map = Map::try_from(PART_TWO).unwrap();
+        let steps = map.parallel_steps();
+
+        assert_eq!(steps, Ok(6));
+        let ghost = ghost(&map, "22A");
+        assert_eq!(
+            ghost,
+            Ghost {
+                start: Node('2', '2', 'A'),
+                tail: 2,
+                cycle: 6,
+                tail_hits: vec![],
+                cycle_hits: vec![3, 6],
+            }
+        );
+        assert_eq!(ghost.lcm_shortcut(), Ok(3));
+    }
+
+    #[test]
+    fn lcm_shortcut_broken() {
+        let input = r#"L
+
+11A = (11Z, 11Z)
+11Z = (11B, 11B)
+11B = (11C, 11C)
+11C = (11Z, 11Z)
+22A = (22B, 22B)
+22B = (22Z, 22Z)
+22Z = (22C, 22C)
+22C = (22Z, 22Z)"#;
+
+        let map = Map::try_from(input).unwrap();
+        assert!(ghost(&map, "11A").lcm_shortcut().is_err());
+        assert_eq!(ghost(&map, "22A").lcm_shortcut(), Ok(2));
+        // The lcm of 1 and 2 would say 2
+        assert_eq!(map.parallel_steps(), Ok(4));
+        assert_eq!(together(&[ghost(&map, "11A")]), Some(1));
+        assert_eq!(
+            // After the notes about the missing `AAA` and `ZZZ`
+            Day::notes(&map)[2..],
+            ["the lcm of the first `..Z` of every start would be wrong: 11A isn't on a `..Z` node after 2 steps, a multiple of 1"]
+        );
+    }
+
+    #[test]
+    fn never_together() {
+        let input = r#"L
+
+11A = (11Z, 11Z)
+11Z = (11Z, 11Z)
+22A = (22B, 22B)
+22B = (22B, 22B)"#;
+
+        assert_eq!(
+            Map::try_from(input).unwrap().parallel_steps(),
+            Err(ErrorKind::NeverTogether.into())
+        );
+    }
+
+    #[test]
+    fn chinese_remainder() {
+        assert_eq!(crt((2, 3), (3, 5)), Some((8, 15)));
+        assert_eq!(crt((1, 4), (3, 6)), Some((9, 12)));
+        assert_eq!(crt((0, 4), (1, 6)), None);
+    }
+
+    #[test]
+    fn simulate_agrees() {
+        let map = Map::try_from(PART_TWO).unwrap();
+        let ghosts = [ghost(&map, "11A"), ghost(&map, "22A")];
+
+        assert_eq!(simulate(&ghosts), Some(6));
+        assert_eq!(repeating(&ghosts), Some(6));
+    }
+
+    #[test]
+    fn tables() {
+        let map = Map::try_from(PART_TWO).unwrap();
+        let id = |name| map.id(parse::node(name).unwrap().1).unwrap();
+        let names = |nodes: &Nodes| {
+            nodes
+                .iter()
+                .map(|id| map.names[id as usize].to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(map.next[id("11B") as usize], [id("XXX"), id("11Z")]);
+        assert_eq!(names(&map.starts), ["11A", "22A"]);
+        assert_eq!(names(&map.ends), ["11Z", "22Z"]);
+        assert_eq!(map.jump[id("11A") as usize], id("11Z"));
+        assert_eq!(map.jump[id("22A") as usize], id("22C"));
+        assert_eq!(map.jump[id("22C") as usize], id("22B"));
+        assert_eq!(names(&map.passes_end), ["11A", "11Z", "22B", "22C"]);
+    }
+
+    fn error(input: &str) -> String {
+        Map::try_from(input).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn syntax_errors() {
+        assert_eq!(
+            error(""),
+            "line 1, column 1 of ``: expected a line of `L`s and `R`s"
+        );
+        assert_eq!(
+            error("LRXL\n\n"),
+            "line 1, column 3 of `LRXL`: `X` is not an instruction"
+        );
+        assert_eq!(
+            error(&format!("{}X{}\n\n", "L".repeat(50), "R".repeat(50))),
+            format!(
+                "line 1, column 51 of `…{}X{}…`: `X` is not an instruction",
+                "L".repeat(20),
+                "R".repeat(19)
+            )
+        );
+        assert_eq!(
+            error("LR\nAAA = (AAA, AAA)"),
+            "line 2, column 1 of `AAA = (AAA, AAA)`: expected an empty line after the instructions"
+        );
+        assert_eq!(
+            error("LR"),
+            "line 2, column 1 of ``: expected an empty line after the instructions"
+        );
+        assert_eq!(
+            error("LR\n\nAAA = BBB, CCC)"),
+            "line 3, column 7 of `AAA = BBB, CCC)`: expected `(`"
+        );
+        assert_eq!(
+            error("LR\n\nAAA = (AAA, A)"),
+            "line 3, column 13 of `AAA = (AAA, A)`: expected a node name like `AAA`"
+        );
+        assert_eq!(
+            error("LR\n\nAAA = (AAA, AAA) # loops"),
+            "line 3, column 17 of `AAA = (AAA, AAA) # loops`: expected the end of the line"
+        );
+    }
+
+    #[test]
+    fn validation() {
+        assert_eq!(
+            error("L\n\nAAA = (AAA, AAA)\nBBB = (CCC, AAA)"),
+            "line 4, column 8 of `BBB = (CCC, AAA)`: `CCC` is used but never defined"
+        );
+        assert_eq!(
+            error("L\n\nAAA = (AAA, AAA)\nBBB = (AAA, AAA)\nAAA = (BBB, BBB)"),
+            "line 5, column 1 of `AAA = (BBB, BBB)`: `AAA` is already defined on line 3"
+        );
+
+        let map = Map::try_from("L\n\nAAA = (AAA, AAA)").unwrap();
+        assert_eq!(
+            map.aaa_to_zzz().unwrap_err().to_string(),
+            "there's no node `ZZZ`"
+        );
+        let map = Map::try_from("L\n\nAAA = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap();
+        assert_eq!(
+            map.aaa_to_zzz().unwrap_err().to_string(),
+            "`ZZZ` can't be reached from `AAA`"
+        );
+        let map = Map::try_from("L\n\nBBB = (BBB, BBB)").unwrap();
+        assert_eq!(
+            map.parallel_steps().unwrap_err().to_string(),
+            "no node ends in `A`"
+        );
+    }
+}
@@ -0,0 +1,136 @@
+use aoc23::Solution;
+use nom::{
+    character::complete::{char, digit1},
+    combinator::{map_res, opt, recognize},
+    multi::separated_list1,
+    sequence::preceded,
+    Finish, IResult,
+};
+use rayon::prelude::*;
+
+// from https://stackoverflow.com/a/74809016
+fn integer(input: &str) -> IResult<&str, i32> {
+    let (i, number) = map_res(recognize(preceded(opt(char('-')), digit1)), |s| {
+        str::parse(s)
+    })(input)?;
+
+    Ok((i, number))
+}
+
+fn history(input: &str) -> IResult<&str, Vec<i32>> {
+    separated_list1(char(' '), integer)(input)
+}
+
+/// The history followed by its differences, down to the first one that's all zeros.
+/// `None` if the differences run out before that.
+fn derivatives(history: &[i32]) -> Option<Vec<Vec<i32>>> {
+    let mut derivatives = vec![history.to_vec()];
+
+    while let Some(last) = derivatives
+        .last()
+        .filter(|last| last.iter().any(|&i| i != 0))
+    {
+        if last.len() < 2 {
+            return None;
+        }
+        let derivative = last.windows(2).map(|pair| pair[1] - pair[0]).collect();
+        derivatives.push(derivative)
+    }
+
+    Some(derivatives)
+}
+
+/// Sums what `extrapolate` makes of every history
+fn sum(
+    histories: &[Vec<i32>],
+    extrapolate: impl Fn(&[Vec<i32>]) -> i32 + Sync,
+) -> aoc23::Result<i32> {
+    histories
+        .par_iter()
+        .enumerate()
+        .map(|(index, history)| {
+            derivatives(history)
+                .map(|derivatives| extrapolate(&derivatives))
+                .ok_or_else(|| {
+                    format!(
+                        "the differences on line {} never settle to zeros",
+                        index + 1
+                    )
+                })
+        })
+        .sum::<Result<_, _>>()
+        .map_err(Into::into)
+}
+
+fn a(histories: &[Vec<i32>]) -> aoc23::Result<i32> {
+    sum(histories, |derivatives| {
+        derivatives
+            .iter()
+            .filter_map(|derivative| derivative.last())
+            .sum()
+    })
+}
+
+fn b(histories: &[Vec<i32>]) -> aoc23::Result<i32> {
+    sum(histories, |derivatives| {
+        derivatives
+            .iter()
+            .rev()
+            .filter_map(|derivative| derivative.first())
+            .fold(0, |acc, curr| curr - acc)
+    })
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Mirage Maintenance";
+
+    type Parsed = Vec<Vec<i32>>;
+    type One = i32;
+    type Two = i32;
+
+    fn parse(input: &str) -> aoc23::Result<Self::Parsed> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| match history(line).finish() {
+                Ok(("", history)) => Ok(history),
+                _ => Err(format!(
+                    "line {}: expected numbers separated by spaces, got `{line}`",
+                    index + 1
+                )
+                .into()),
+            })
+            .collect()
+    }
+
+    fn part_one(histories: &Self::Parsed) -> aoc23::Result<Self::One> {
+        a(histories)
+    }
+
+    fn part_two(histories: &Self::Parsed) -> aoc23::Result<Self::Two> {
+        b(histories)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples() {
+        aoc23::examples::check::<Day>(concat!(env!("CARGO_MANIFEST_DIR"), "/examples"));
+    }
+
+    #[test]
+    fn never_settles() {
+        let histories = Day::parse("0 3 6 9\n1 2 4 8").unwrap();
+        assert_eq!(
+            Day::part_one(&histories).unwrap_err().to_string(),
+            "the differences on line 2 never settle to zeros"
+        );
+        assert!(Day::parse("0 3 x").is_err());
+    }
+}
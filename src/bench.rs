@@ -0,0 +1,89 @@
+//! Tiny statistics over repeated timings, no need for a full benchmarking framework.
+
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub std_dev: Duration,
+}
+
+impl Stats {
+    /// # Panics
+    /// If there are no samples
+    pub fn new(samples: &mut [Duration]) -> Self {
+        assert!(!samples.is_empty(), "at least one sample");
+        samples.sort_unstable();
+
+        let n = samples.len();
+        let median = if n % 2 == 1 {
+            samples[n / 2]
+        } else {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        };
+        let mean = samples.iter().map(Duration::as_secs_f64).sum::<f64>() / n as f64;
+        let variance = samples
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / n as f64;
+
+        Stats {
+            samples: n,
+            min: samples[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Calls `f` up to `iterations` times, stopping early once `budget` is used up.
+/// There's always at least one call.
+pub fn measure<T>(iterations: usize, budget: Duration, mut f: impl FnMut() -> T) -> Stats {
+    let started = Instant::now();
+    let mut samples = Vec::with_capacity(iterations);
+    loop {
+        let start = Instant::now();
+        black_box(f());
+        samples.push(start.elapsed());
+
+        if samples.len() >= iterations || started.elapsed() >= budget {
+            break;
+        }
+    }
+
+    Stats::new(&mut samples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let mut samples = [4, 1, 3, 2].map(Duration::from_millis);
+        let stats = Stats::new(&mut samples);
+
+        assert_eq!(stats.samples, 4);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean.as_micros(), 2500);
+        // sqrt(1.25) ms
+        assert_eq!(stats.std_dev.as_micros(), 1118);
+    }
+
+    #[test]
+    fn measure_at_least_once() {
+        let mut calls = 0;
+        let stats = measure(100, Duration::ZERO, || calls += 1);
+        assert_eq!(calls, 1);
+        assert_eq!(stats.samples, 1);
+    }
+}
@@ -0,0 +1,87 @@
+//! Example inputs from the puzzle descriptions, used as test fixtures.
+//!
+//! Every `<name>.txt` in a day's `examples` folder is an input,
+//! the `<name>.answers` next to it holds `<part> <answer>` lines for the parts the example has answers for.
+
+use std::{fs, path::Path};
+
+use crate::{Answer, Part, Solution};
+
+/// Runs `S` over every example in `dir` and compares the answers.
+///
+/// Parts that aren't solved yet are skipped, so examples can be added before the solution.
+///
+/// # Panics
+/// If any answer is wrong, or an example is missing its `.answers`
+pub fn check<S: Solution>(dir: impl AsRef<Path>) {
+    let dir = dir.as_ref();
+    let Ok(entries) = fs::read_dir(dir) else {
+        eprintln!("no examples in {}", dir.display());
+        return;
+    };
+
+    let mut examples: Vec<_> = entries
+        .map(|entry| entry.expect("the examples to be listable").path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "txt"))
+        .collect();
+    examples.sort();
+
+    let mut failures = Vec::new();
+    for example in &examples {
+        let name = example.file_stem().unwrap().to_string_lossy();
+        let answers = match fs::read_to_string(example.with_extension("answers")) {
+            Ok(answers) => answers,
+            Err(error) => {
+                failures.push(format!("{name}: couldn't read its answers: {error}"));
+                continue;
+            }
+        };
+        let input = fs::read_to_string(example).expect("the example to be readable");
+        let parsed = match S::parse(&input) {
+            Ok(parsed) => parsed,
+            Err(error) => {
+                failures.push(format!("{name}: couldn't parse: {error}"));
+                continue;
+            }
+        };
+
+        for line in answers.lines().filter(|line| !line.trim().is_empty()) {
+            let Some((part, expected)) = line.trim().split_once(' ') else {
+                failures.push(format!("{name}: expected `<part> <answer>`, got `{line}`"));
+                continue;
+            };
+            let part: Part = match part.parse() {
+                Ok(part) => part,
+                Err(error) => {
+                    failures.push(format!("{name}: {error}"));
+                    continue;
+                }
+            };
+
+            let actual = match part {
+                Part::One => S::part_one(&parsed).map(|answer| answer.answer()),
+                Part::Two => S::part_two(&parsed).map(|answer| answer.answer()),
+            };
+            let actual = match actual {
+                Ok(actual) => actual,
+                Err(error) => {
+                    failures.push(format!("{name} part {part}: {error}"));
+                    continue;
+                }
+            };
+            match actual {
+                Some(actual) if actual == expected.trim() => {}
+                Some(actual) => failures.push(format!(
+                    "{name} part {part}: expected {}, got {actual}",
+                    expected.trim()
+                )),
+                None => eprintln!("skipping {name} part {part}: unsolved"),
+            }
+        }
+    }
+
+    if examples.is_empty() {
+        eprintln!("no examples in {}", dir.display());
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
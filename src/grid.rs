@@ -0,0 +1,376 @@
+//! Rectangular grids of cells, as most of the map puzzles come in.
+//!
+//! Positions are `(x, y)` with `(0, 0)` in the top left corner and `y` growing downwards.
+
+use std::{
+    error::Error,
+    fmt::{Debug, Display},
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+/// `(x, y)`
+pub type Point = (usize, usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Heading {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Heading {
+    /// Clockwise, starting with north
+    pub const ALL: [Heading; 4] = [Heading::North, Heading::East, Heading::South, Heading::West];
+
+    pub fn reverse(self) -> Self {
+        match self {
+            Heading::North => Heading::South,
+            Heading::East => Heading::West,
+            Heading::South => Heading::North,
+            Heading::West => Heading::East,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Heading::North => Heading::East,
+            Heading::East => Heading::South,
+            Heading::South => Heading::West,
+            Heading::West => Heading::North,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.turn_right().reverse()
+    }
+
+    /// `(dx, dy)` of a single step
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Heading::North => (0, -1),
+            Heading::East => (1, 0),
+            Heading::South => (0, 1),
+            Heading::West => (-1, 0),
+        }
+    }
+}
+
+/// The eight offsets around a cell, row by row
+const SURROUNDING: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// # Panics
+    /// If there aren't exactly `width * height` cells
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "a {width}x{height} grid");
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// One cell per character, one row per line. Every line has to be as long as the first.
+    pub fn parse<E>(
+        input: &str,
+        mut cell: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Self, GridError<E>> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::with_capacity(input.len());
+
+        for (y, line) in input.lines().enumerate() {
+            let before = cells.len();
+            for (x, char) in line.chars().enumerate() {
+                cells.push(cell(char).map_err(|source| GridError::Cell { x, y, source })?);
+            }
+
+            let found = cells.len() - before;
+            match width {
+                None => width = Some(found),
+                Some(expected) if expected != found => {
+                    return Err(GridError::Ragged { y, expected, found })
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or_default(),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Point) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// `None` outside of the grid
+    pub fn get(&self, position: Point) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[self.offset(position)])
+    }
+
+    /// `None` outside of the grid
+    pub fn get_mut(&mut self, position: Point) -> Option<&mut T> {
+        if self.contains(position) {
+            let offset = self.offset(position);
+            Some(&mut self.cells[offset])
+        } else {
+            None
+        }
+    }
+
+    fn offset(&self, (x, y): Point) -> usize {
+        y * self.width + x
+    }
+
+    /// Moves `distance` cells into `heading`, `None` when that leaves the grid
+    pub fn step(&self, (x, y): Point, heading: Heading, distance: usize) -> Option<Point> {
+        let (dx, dy) = heading.offset();
+        let moved = (
+            x.checked_add_signed(dx.checked_mul(distance as isize)?)?,
+            y.checked_add_signed(dy.checked_mul(distance as isize)?)?,
+        );
+        self.contains(moved).then_some(moved)
+    }
+
+    /// The up to four cells sharing an edge with `position`, clockwise from north
+    pub fn neighbours(&self, position: Point) -> impl Iterator<Item = Point> + '_ {
+        Heading::ALL
+            .into_iter()
+            .filter_map(move |heading| self.step(position, heading, 1))
+    }
+
+    /// The up to eight cells sharing an edge or a corner with `position`, row by row
+    pub fn neighbours_with_diagonals(&self, (x, y): Point) -> impl Iterator<Item = Point> + '_ {
+        SURROUNDING.into_iter().filter_map(move |(dx, dy)| {
+            let moved = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+            self.contains(moved).then_some(moved)
+        })
+    }
+
+    /// Top to bottom
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `max(1)` so an empty grid doesn't trip up `chunks`
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// # Panics
+    /// If `y` is outside of the grid
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(
+            y < self.height,
+            "row {y} of a grid with {} rows",
+            self.height
+        );
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Left to right
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Top to bottom
+    ///
+    /// # Panics
+    /// If `x` is outside of the grid
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {x} of a grid with {} columns",
+            self.width
+        );
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Every cell with its position, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(offset, cell)| ((offset % width, offset / width), cell))
+    }
+
+    /// The first cell row by row that matches `predicate`
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find_map(|(position, cell)| predicate(cell).then_some(position))
+    }
+}
+
+/// # Panics
+/// If the position is outside of the grid, use [`Grid::get`] for a checked lookup
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Point) -> &Self::Output {
+        self.get(position).unwrap_or_else(|| {
+            panic!(
+                "{position:?} is outside of a {}x{} grid",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, position: Point) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{position:?} is outside of a {width}x{height} grid"))
+    }
+}
+
+/// Any cell type that can be made from a single character
+impl<T: TryFrom<char>> FromStr for Grid<T> {
+    type Err = GridError<T::Error>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse(s, T::try_from)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError<E> {
+    /// A line that's longer or shorter than the first one
+    Ragged {
+        y: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// A character that isn't a valid cell
+    Cell { x: usize, y: usize, source: E },
+}
+
+impl<E: Display> Display for GridError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridError::Ragged { y, expected, found } => write!(
+                f,
+                "line {} is {found} cells wide, expected {expected} like the first",
+                y + 1
+            ),
+            GridError::Cell { x, y, source } => {
+                write!(f, "line {}, column {}: {source}", y + 1, x + 1)
+            }
+        }
+    }
+}
+
+/// The cause is part of the message, so cells can fail with a plain `String`
+impl<E: Display + Debug> Error for GridError<E> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GRID: &str = "abc\ndef\n";
+
+    #[test]
+    fn parse() {
+        let grid: Grid<char> = GRID.parse().unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 0)], 'c');
+        assert_eq!(grid[(0, 1)], 'd');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, 2)), None);
+
+        assert_eq!(
+            "abc\nde".parse::<Grid<char>>(),
+            Err(GridError::Ragged {
+                y: 1,
+                expected: 3,
+                found: 2
+            })
+        );
+        assert!(matches!(
+            "ab\nc€".parse::<Grid<u8>>(),
+            Err(GridError::Cell { x: 1, y: 1, .. })
+        ));
+        assert_eq!("".parse::<Grid<char>>().unwrap().height(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_past_the_row() {
+        let grid: Grid<char> = GRID.parse().unwrap();
+        let _ = grid[(3, 0)];
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid: Grid<char> = GRID.parse().unwrap();
+        assert_eq!(
+            grid.neighbours((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours((1, 1)).collect::<Vec<_>>(),
+            [(1, 0), (2, 1), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours_with_diagonals((2, 0)).collect::<Vec<_>>(),
+            [(1, 0), (1, 1), (2, 1)]
+        );
+        assert_eq!(grid.neighbours_with_diagonals((1, 0)).count(), 5);
+    }
+
+    #[test]
+    fn step() {
+        let grid: Grid<char> = GRID.parse().unwrap();
+        assert_eq!(grid.step((0, 0), Heading::East, 2), Some((2, 0)));
+        assert_eq!(grid.step((0, 0), Heading::East, 3), None);
+        assert_eq!(grid.step((0, 0), Heading::North, 1), None);
+        assert_eq!(grid.step((0, 1), Heading::North, 1), Some((0, 0)));
+        assert_eq!(Heading::North.turn_left(), Heading::West);
+        assert_eq!(Heading::West.turn_right(), Heading::North);
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid: Grid<char> = GRID.parse().unwrap();
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            [['a', 'b', 'c'], ['d', 'e', 'f']]
+        );
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(
+            grid.columns()
+                .map(|column| column.collect::<String>())
+                .collect::<Vec<_>>(),
+            ["ad", "be", "cf"]
+        );
+        assert_eq!(grid.position(|&cell| cell == 'e'), Some((1, 1)));
+    }
+}
@@ -0,0 +1,166 @@
+use std::{
+    error::Error,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Where puzzle inputs are read from, one `<day>.txt` per day.
+///
+/// Directories are searched in order, the last one is where fetched inputs get cached.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Inputs {
+    dirs: Vec<PathBuf>,
+}
+
+impl Inputs {
+    /// Overrides the default directories
+    pub const ENV: &'static str = "AOC_INPUTS";
+
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Inputs {
+            dirs: vec![dir.into()],
+        }
+    }
+
+    /// Searched in the given order, panics without any directory
+    pub fn with_dirs(dirs: impl IntoIterator<Item = impl Into<PathBuf>>) -> Self {
+        let dirs: Vec<_> = dirs.into_iter().map(Into::into).collect();
+        assert!(!dirs.is_empty(), "at least one input directory");
+        Inputs { dirs }
+    }
+
+    /// `$AOC_INPUTS` if it's set.
+    /// The `inputs` folder of the workspace, followed by [`user_dir`] otherwise.
+    pub fn from_env() -> Self {
+        match std::env::var_os(Self::ENV) {
+            Some(dir) => Self::new(dir),
+            None => Self::with_dirs(
+                std::iter::once(PathBuf::from(concat!(
+                    env!("CARGO_MANIFEST_DIR"),
+                    "/inputs"
+                )))
+                .chain(user_dir()),
+            ),
+        }
+    }
+
+    pub fn dirs(&self) -> &[PathBuf] {
+        &self.dirs
+    }
+
+    /// Where a fetched input for `day` should be stored
+    pub fn cache_path(&self, day: u8) -> PathBuf {
+        self.dirs
+            .last()
+            .expect("at least one input directory")
+            .join(file_name(day))
+    }
+
+    /// The first existing input for `day`
+    pub fn find(&self, day: u8) -> Option<PathBuf> {
+        self.dirs
+            .iter()
+            .map(|dir| dir.join(file_name(day)))
+            .find(|path| path.is_file())
+    }
+
+    pub fn load(&self, day: u8) -> Result<String, InputError> {
+        match self.find(day) {
+            Some(path) => read(&path),
+            None => Err(InputError::MissingDay {
+                day,
+                paths: self
+                    .dirs
+                    .iter()
+                    .map(|dir| dir.join(file_name(day)))
+                    .collect(),
+            }),
+        }
+    }
+}
+
+/// The per-user directory inputs are cached in, e.g. `~/.local/share/aoc23/inputs` on Linux
+pub fn user_dir() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("aoc23").join("inputs"))
+}
+
+fn file_name(day: u8) -> String {
+    format!("{day}.txt")
+}
+
+/// Reads an input from a file, or from stdin if `path` is `-`.
+pub fn read(path: &Path) -> Result<String, InputError> {
+    if path == Path::new("-") {
+        return io::read_to_string(io::stdin()).map_err(|source| InputError::Io {
+            path: path.to_owned(),
+            source,
+        });
+    }
+
+    fs::read_to_string(path).map_err(|source| match source.kind() {
+        io::ErrorKind::NotFound => InputError::Missing(path.to_owned()),
+        _ => InputError::Io {
+            path: path.to_owned(),
+            source,
+        },
+    })
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    MissingDay { day: u8, paths: Vec<PathBuf> },
+    Missing(PathBuf),
+    Io { path: PathBuf, source: io::Error },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::MissingDay { day, paths } => {
+                write!(f, "no input for day {day}, looked for")?;
+                for (i, path) in paths.iter().enumerate() {
+                    let separator = if i == 0 { "" } else { "," };
+                    write!(f, "{separator} {}", path.display())?;
+                }
+                write!(
+                    f,
+                    " (run `aoc fetch {day}`, set ${} or pass --input)",
+                    Inputs::ENV
+                )
+            }
+            InputError::Missing(path) => write!(f, "no input at {}", path.display()),
+            InputError::Io { path, source } => {
+                write!(f, "couldn't read {}: {source}", path.display())
+            }
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_day() {
+        let inputs = Inputs::new("does/not/exist");
+        let error = inputs.load(26).unwrap_err();
+        assert!(matches!(error, InputError::MissingDay { day: 26, .. }));
+        assert!(error.to_string().contains("26.txt"));
+    }
+
+    #[test]
+    fn workspace_inputs() {
+        let inputs = Inputs::new(concat!(env!("CARGO_MANIFEST_DIR"), "/inputs"));
+        assert!(inputs.load(1).unwrap().lines().count() > 0);
+    }
+}
@@ -0,0 +1,236 @@
+pub mod bench;
+pub mod examples;
+pub mod grid;
+pub mod input;
+
+pub use input::Inputs;
+
+use std::{
+    any::Any, collections::BTreeMap, error::Error, fmt::Display, marker::PhantomData, str::FromStr,
+};
+
+pub type Result<T, E = Box<dyn Error + Send + Sync>> = std::result::Result<T, E>;
+
+/// A solution to one day of the calendar.
+///
+/// The input is parsed once and then shared by both parts.
+/// A part fails when the input parses but doesn't have what that part needs.
+pub trait Solution {
+    /// The day of december the puzzle was released on
+    const DAY: u8;
+    /// The puzzle's title, without the `--- Day N: … ---` around it
+    const TITLE: &'static str;
+
+    type Parsed: 'static;
+    type One: Answer;
+    type Two: Answer;
+
+    fn parse(input: &str) -> Result<Self::Parsed>;
+    fn part_one(parsed: &Self::Parsed) -> Result<Self::One>;
+    fn part_two(parsed: &Self::Parsed) -> Result<Self::Two>;
+
+    /// Anything worth knowing about the input besides the answers, like a shortcut that wouldn't work on it.
+    /// The runner prints them next to the answers.
+    fn notes(_parsed: &Self::Parsed) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// Anything a part can return.
+pub trait Answer {
+    /// Whether the part has been implemented at all
+    const SOLVED: bool = true;
+
+    /// `None` if the part hasn't been solved
+    fn answer(&self) -> Option<String>;
+}
+
+impl<T: Display> Answer for T {
+    fn answer(&self) -> Option<String> {
+        Some(self.to_string())
+    }
+}
+
+/// Placeholder answer for a part that hasn't been solved yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolved;
+
+impl Answer for Unsolved {
+    const SOLVED: bool = false;
+
+    fn answer(&self) -> Option<String> {
+        None
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim() {
+            "1" | "one" | "a" => Ok(Part::One),
+            "2" | "two" | "b" => Ok(Part::Two),
+            _ => Err(format!("`{s}` isn't a part, it's either 1 or 2")),
+        }
+    }
+}
+
+/// Type erased [`Solution`], so days with different answer types can live side by side.
+pub trait Puzzle {
+    fn day(&self) -> u8;
+    fn title(&self) -> &'static str;
+    /// Whether the part returns an answer, without needing an input to find out
+    fn implemented(&self, part: Part) -> bool;
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>>;
+    /// `Ok(None)` if the part hasn't been solved
+    fn part_one(&self, parsed: &dyn Any) -> Result<Option<String>>;
+    fn part_two(&self, parsed: &dyn Any) -> Result<Option<String>>;
+    /// See [`Solution::notes`]
+    fn notes(&self, parsed: &dyn Any) -> Vec<String>;
+
+    fn solve(&self, part: Part, parsed: &dyn Any) -> Result<Option<String>> {
+        match part {
+            Part::One => self.part_one(parsed),
+            Part::Two => self.part_two(parsed),
+        }
+    }
+}
+
+struct Erased<S>(PhantomData<fn() -> S>);
+
+impl<S: Solution> Erased<S> {
+    fn downcast(parsed: &dyn Any) -> &S::Parsed {
+        parsed
+            .downcast_ref()
+            .unwrap_or_else(|| panic!("input to have been parsed by day {}", S::DAY))
+    }
+}
+
+impl<S: Solution> Puzzle for Erased<S> {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn implemented(&self, part: Part) -> bool {
+        match part {
+            Part::One => S::One::SOLVED,
+            Part::Two => S::Two::SOLVED,
+        }
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn part_one(&self, parsed: &dyn Any) -> Result<Option<String>> {
+        Ok(S::part_one(Self::downcast(parsed))?.answer())
+    }
+
+    fn part_two(&self, parsed: &dyn Any) -> Result<Option<String>> {
+        Ok(S::part_two(Self::downcast(parsed))?.answer())
+    }
+
+    fn notes(&self, parsed: &dyn Any) -> Vec<String> {
+        S::notes(Self::downcast(parsed))
+    }
+}
+
+/// All known days, looked up by their number.
+#[derive(Default)]
+pub struct Registry {
+    days: BTreeMap<u8, Box<dyn Puzzle>>,
+}
+
+impl Registry {
+    /// # Panics
+    /// If a solution for the same day has already been registered
+    pub fn register<S: Solution + 'static>(&mut self) -> &mut Self {
+        let previous = self.days.insert(S::DAY, Box::new(Erased::<S>(PhantomData)));
+        assert!(previous.is_none(), "day {} registered twice", S::DAY);
+        self
+    }
+
+    pub fn get(&self, day: u8) -> Option<&dyn Puzzle> {
+        self.days.get(&day).map(Box::as_ref)
+    }
+
+    /// In order of their day
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Puzzle> {
+        self.days.values().map(Box::as_ref)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Echo;
+
+    impl Solution for Echo {
+        const DAY: u8 = 26;
+        const TITLE: &'static str = "Echo";
+
+        type Parsed = usize;
+        type One = usize;
+        type Two = Unsolved;
+
+        fn parse(input: &str) -> Result<Self::Parsed> {
+            Ok(input.parse()?)
+        }
+
+        fn part_one(parsed: &Self::Parsed) -> Result<Self::One> {
+            Ok(*parsed * 2)
+        }
+
+        fn part_two(_: &Self::Parsed) -> Result<Self::Two> {
+            Ok(Unsolved)
+        }
+    }
+
+    #[test]
+    fn lookup() {
+        let mut registry = Registry::default();
+        registry.register::<Echo>();
+
+        let echo = registry.get(26).unwrap();
+        let parsed = echo.parse("21").unwrap();
+        assert_eq!(
+            echo.part_one(parsed.as_ref()).unwrap().as_deref(),
+            Some("42")
+        );
+        assert_eq!(echo.part_two(parsed.as_ref()).unwrap(), None);
+        assert_eq!(echo.title(), "Echo");
+        assert!(echo.implemented(Part::One));
+        assert!(!echo.implemented(Part::Two));
+        assert!(registry.get(1).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn register_twice() {
+        Registry::default().register::<Echo>().register::<Echo>();
+    }
+}